@@ -9,7 +9,8 @@ use crate::{
         constants::{
             command::DEFAULT_COMMAND_PREFIX,
             general::{
-                EXPLORER_KEY_1, EXPLORER_KEY_2, INPUT_KEY_1, INPUT_KEY_2, QUIT_KEY_1, QUIT_KEY_2,
+                EVENT_POLL_INTERVAL, EXPLORER_KEY_1, EXPLORER_KEY_2, INPUT_KEY_1, INPUT_KEY_2,
+                QUIT_KEY_1, QUIT_KEY_2,
             },
         },
         errors::commands::CommandError,
@@ -50,6 +51,10 @@ impl App {
 
     pub fn run(mut self, mut terminal: DefaultTerminal) -> color_eyre::Result<()> {
         while self.application_state.borrow().is_running() {
+            let _ = self
+                .chart_view_widget
+                .poll_jobs()
+                .is_err_and(|err| self.application_state.borrow_mut().set_error(Some(err)));
             terminal.draw(|f| {
                 let size = f.area();
                 let main_chunks = Layout::default()
@@ -95,8 +100,14 @@ impl App {
     }
 
     fn handle_crossterm_events(&mut self) -> color_eyre::Result<()> {
+        if !event::poll(EVENT_POLL_INTERVAL)? {
+            return Ok(());
+        }
         match event::read()? {
             Event::Key(key) if key.kind == KeyEventKind::Press => {
+                if key.code == KeyCode::Esc && self.chart_view_widget.cancel_job() {
+                    return Ok(());
+                }
                 let mode = self.application_state.borrow().mode();
                 match mode {
                     ApplicationMode::Explorer => {
@@ -0,0 +1,64 @@
+use anyhow::Result;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+};
+
+use crate::{
+    clients::traits::file_write_only::FileWriteOnly,
+    models::chart_view::chart::{chart_model::ChartModel, octave_spectrum::OctaveSpectrum},
+    shared::constants::csv::{CSV_OCTAVE_HEADER, CSV_OCTAVE_TOTAL, CSV_POINTS_HEADER},
+};
+
+pub struct CsvWritingClient;
+
+impl Default for CsvWritingClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvWritingClient {
+    pub fn new() -> Self {
+        CsvWritingClient
+    }
+
+    /// Octave band table
+    ///
+    /// One row per band with its nominal and exact center, edges and level,
+    /// followed by the total level row.
+    ///
+    /// ---
+    ///
+    /// * `writer`: The destination of the rows.
+    /// * `octave`: The band levels to be written.
+    fn write_octave<W: Write>(&self, writer: &mut W, octave: &OctaveSpectrum) -> Result<()> {
+        writeln!(writer, "{}", CSV_OCTAVE_HEADER)?;
+        for band in &octave.bands {
+            writeln!(
+                writer,
+                "{},{},{},{},{}",
+                band.nominal, band.center, band.lower, band.upper, band.level
+            )?;
+        }
+        writeln!(writer, "{},,,,{}", CSV_OCTAVE_TOTAL, octave.total)?;
+        Ok(())
+    }
+}
+
+impl FileWriteOnly for CsvWritingClient {
+    fn write_chart_file(&self, path: &str, chart: &ChartModel) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        match &chart.octave {
+            Some(octave) => self.write_octave(&mut writer, octave)?,
+            None => {
+                writeln!(writer, "{}", CSV_POINTS_HEADER)?;
+                for point in &chart.data {
+                    writeln!(writer, "{},{}", point.x, point.y)?;
+                }
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
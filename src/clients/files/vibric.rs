@@ -108,6 +108,7 @@ impl VibricReadingClient {
                     .to_str()
                     .unwrap_or_default(),
             ),
+            signal.header.clone(),
         );
         Ok(parsed_data)
     }
@@ -116,12 +117,14 @@ impl VibricReadingClient {
 impl FileReadOnly for VibricReadingClient {
     fn parse_signal_file(&self, path: &str, channel: usize) -> Result<ChartModel> {
         let parsed_data = self.parse_bin_file(path, channel)?;
-        Ok(ChartModel::new(
+        let mut chart = ChartModel::new(
             parsed_data.data,
             GraphType::Line,
             parsed_data.sample_rate,
             &parsed_data.chart_title,
             None,
-        ))
+        );
+        chart.header = Some(parsed_data.header);
+        Ok(chart)
     }
 }
@@ -2,7 +2,8 @@ use anyhow::Result;
 
 use crate::models::chart_view::chart::chart_model::ChartModel;
 
-pub trait FileReadOnly {
+/// File readers are shared with the processing worker for batch runs.
+pub trait FileReadOnly: Send + Sync {
     /// Parse data file
     ///
     /// Collects signal data from a file and returns a vector of points.
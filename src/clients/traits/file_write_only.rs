@@ -0,0 +1,13 @@
+use anyhow::Result;
+
+use crate::models::chart_view::chart::chart_model::ChartModel;
+
+pub trait FileWriteOnly {
+    /// Write data file
+    ///
+    /// Stores the chart data to a file, replacing it when it exists.
+    ///
+    /// * `path`: The path to the file to be written.
+    /// * `chart`: The chart to be stored.
+    fn write_chart_file(&self, path: &str, chart: &ChartModel) -> Result<()>;
+}
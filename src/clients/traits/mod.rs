@@ -1 +1,2 @@
 pub mod file_read_only;
+pub mod file_write_only;
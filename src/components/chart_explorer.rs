@@ -13,6 +13,7 @@ use crate::{
         errors::{commands::CommandError, files::FileError},
     },
     states::app::{ApplicationMode, ApplicationState},
+    utils::command_args::path_arg,
 };
 use anyhow::{Result, anyhow};
 use crossterm::event::{KeyCode, KeyEvent};
@@ -25,11 +26,7 @@ use ratatui::{
 };
 use std::cell::{RefCell, RefMut};
 use std::rc::Rc;
-use std::{
-    collections::HashMap,
-    path::{Path, PathBuf},
-    str::FromStr,
-};
+use std::{collections::HashMap, path::PathBuf, str::FromStr};
 
 pub struct ChartExplorerComponent {
     app_state: Rc<RefCell<ApplicationState>>,
@@ -122,16 +119,8 @@ impl Component for ChartExplorerComponent {
                 state_borrow.change_current_chart(points_arg);
             }
             ChartExplorerCommands::OpenFile => {
-                let file_path = if let Some(path_arg) = args.get(1) {
-                    Path::new(path_arg)
-                } else {
-                    return Err(CommandError::NotEnoughArguments.into());
-                };
-                if !file_path.exists() {
-                    let file_name = file_path.to_str().unwrap_or_default();
-                    return Err(CommandError::InvalidArguments(String::from(file_name)).into());
-                }
-                self.add_chart_from_file(file_path.to_path_buf(), &mut state_borrow)?;
+                let file_path = path_arg(&args, 1)?;
+                self.add_chart_from_file(file_path, &mut state_borrow)?;
             }
         };
         state_borrow.set_command(None);
@@ -150,7 +139,7 @@ impl Component for ChartExplorerComponent {
             .charts()
             .iter()
             .map(|chart_rc| {
-                let chart = chart_rc.borrow();
+                let chart = chart_rc.read();
                 let title_line = Line::from(Span::styled(
                     chart.metadata.title.clone(),
                     Style::default()
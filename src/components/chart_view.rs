@@ -1,46 +1,135 @@
 use crate::{
+    clients::{
+        files::{csv::CsvWritingClient, vibric::VibricReadingClient},
+        traits::{file_read_only::FileReadOnly, file_write_only::FileWriteOnly},
+    },
     components::component::Component,
-    models::chart_view::chart::{chart_model::ChartModel, chart_transform::ChartTransform},
-    services::chart_processor::{ChartProcessingService, FftFilterType},
+    models::{
+        chart_view::{
+            chart::{
+                chart_model::ChartModel, heatmap::Heatmap, octave_spectrum::OctaveSpectrum,
+                signal_statistics::SignalStatistics, signal_unit::SignalUnit,
+                spectral_peak::SpectralPeak,
+            },
+            colormap::Colormap,
+            pipeline::{Pipeline, PipelineAction, SaveMode},
+            statistics_scope::StatisticsScope,
+        },
+        files::file_types::FileType,
+    },
+    services::{
+        chart_processor::ChartProcessingService,
+        handlers::{self, ProcessingRequest, pipeline},
+        worker::{ProcessingWorker, RunningJob},
+    },
     shared::{
-        commands::chart_view::ChartViewCommands,
+        commands::chart_view::{ChartViewCommands, ViewCommands},
         constants::{
-            chart_view::{DEFAULT_CHART_X_MOVE, DEFAULT_CHART_ZOOM_MULTIPLIER},
+            chart_view::{
+                DEFAULT_CHART_X_MOVE, DEFAULT_CHART_ZOOM_MULTIPLIER, HEATMAP_BOUNDARY_SYMBOL,
+                HEATMAP_CELL_SYMBOL, HEATMAP_LEGEND_WIDTH, JOB_CANCEL_HINT, JOB_CANCELLING_HINT,
+                JOB_PROGRESS_HEIGHT, OCTAVE_BAR_GAP, OCTAVE_PANEL_TITLE, OCTAVE_TOTAL_LABEL,
+                PEAK_PANEL_TITLE, SIDE_PANEL_WIDTH, STATISTICS_PANEL_HEIGHT,
+                STATISTICS_PANEL_TITLE,
+            },
             command::DEFAULT_COMMAND_PREFIX,
+            pipeline::PIPELINE_COMMENT_PREFIX,
         },
-        errors::commands::CommandError,
+        errors::{commands::CommandError, files::FileError, pipeline::PipelineError},
     },
     states::{app::ApplicationState, chart_view::ChartViewState},
+    utils::command_args::{optional_arg, path_arg, required_arg},
 };
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     symbols::{self, Marker},
+    text::Line,
     widgets::{
-        Axis, Block, Borders, Chart, Dataset,
+        Axis, Bar, BarChart, BarGroup, Block, Borders, Chart, Dataset, GraphType, LineGauge, Row,
+        Table,
         canvas::{self, Canvas, Context},
     },
 };
-use std::{cell::RefCell, rc::Rc, str::FromStr};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    str::FromStr,
+    sync::Arc,
+};
 
 pub struct ChartViewComponent {
     state: ChartViewState,
     service: ChartProcessingService,
+    worker: ProcessingWorker,
+    file_writers: HashMap<FileType, Box<dyn FileWriteOnly>>,
+    file_parsers: Arc<HashMap<FileType, Box<dyn FileReadOnly>>>,
     app_state: Rc<RefCell<ApplicationState>>,
 }
 
 impl ChartViewComponent {
     pub fn new(app_state: Rc<RefCell<ApplicationState>>) -> Self {
+        let mut file_writers: HashMap<FileType, Box<dyn FileWriteOnly>> = HashMap::new();
+        file_writers.insert(FileType::Csv, Box::new(CsvWritingClient::new()));
+        let mut file_parsers: HashMap<FileType, Box<dyn FileReadOnly>> = HashMap::new();
+        file_parsers.insert(FileType::Vibric, Box::new(VibricReadingClient::new()));
         Self {
             service: ChartProcessingService::new(),
+            worker: ProcessingWorker::new(),
             state: ChartViewState::new(),
+            file_writers,
+            file_parsers: Arc::new(file_parsers),
             app_state,
         }
     }
 
+    /// Collect the running job
+    ///
+    /// Add the charts of a finished job to the application state.
+    /// Errors of the job are returned to be shown in the console.
+    pub fn poll_jobs(&mut self) -> Result<()> {
+        let Some(result) = self.worker.poll() else {
+            return Ok(());
+        };
+        let mut state_borrow = self.app_state.borrow_mut();
+        for chart in result? {
+            state_borrow.add_chart(chart);
+        }
+        Ok(())
+    }
+
+    /// Cancel the running job, returns `false` if there was none.
+    pub fn cancel_job(&mut self) -> bool {
+        self.worker.cancel()
+    }
+
+    /// Export chart
+    ///
+    /// Write the chart to a file with the writer matching its extension
+    ///
+    /// ---
+    ///
+    /// * `path`: The path to the file to write
+    /// * `chart`: The chart to export
+    fn export_chart(&self, path: &Path, chart: &ChartModel) -> Result<()> {
+        let extension = if let Some(extension) = path.extension() {
+            extension.to_str().ok_or(FileError::ExtensionParseError)?
+        } else {
+            return Err(anyhow!(FileError::PathParseError));
+        };
+        let writer = self
+            .file_writers
+            .get(&FileType::from_str(extension).map_err(|_| FileError::UnsupportedType)?)
+            .ok_or(FileError::UnsupportedType)?;
+        writer.write_chart_file(path.to_str().ok_or(FileError::PathParseError)?, chart)
+    }
+
     /// Create numeric labels for the chart
     ///
     /// This function generates numeric labels for the x and y axes of the chart.
@@ -96,6 +185,304 @@ impl ChartViewComponent {
             ));
         });
     }
+
+    /// Render signal statistics panel.
+    ///
+    /// This function lists the condition indicators of the whole chart or
+    /// of its visible range, along with the extremes stored in the file header.
+    ///
+    /// ---
+    ///
+    /// * `f`: The frame to render the panel on.
+    /// * `rect`: The rectangle to render the panel in.
+    /// * `scope`: The part of the chart the statistics describe.
+    /// * `statistics`: The statistics to list, if any point is in scope.
+    fn render_statistics(
+        &self,
+        f: &mut Frame,
+        rect: Rect,
+        scope: StatisticsScope,
+        statistics: Option<SignalStatistics>,
+    ) {
+        let style = Style::default().fg(self.state.canvas_style().canvas_color);
+        let rows: Vec<Row> = match statistics {
+            Some(statistics) => {
+                let mut values = vec![
+                    ("Samples", statistics.count.to_string()),
+                    ("Mean", format!("{:.6}", statistics.mean)),
+                    ("RMS", format!("{:.6}", statistics.rms)),
+                    ("Std", format!("{:.6}", statistics.std)),
+                    ("Peak", format!("{:.6}", statistics.peak)),
+                    ("Peak-to-peak", format!("{:.6}", statistics.peak_to_peak)),
+                    ("Crest factor", format!("{:.4}", statistics.crest_factor)),
+                    ("Kurtosis", format!("{:.4}", statistics.kurtosis)),
+                    ("Skewness", format!("{:.4}", statistics.skewness)),
+                ];
+                if let (Some(max), Some(min)) = (statistics.header_max, statistics.header_min) {
+                    values.push(("Header max", format!("{:.6}", max)));
+                    values.push(("Header min", format!("{:.6}", min)));
+                }
+                values
+                    .into_iter()
+                    .map(|(name, value)| Row::new(vec![String::from(name), value]))
+                    .collect()
+            }
+            None => vec![Row::new(vec!["No data in range", ""])],
+        };
+        let table = Table::new(rows, [Constraint::Length(14), Constraint::Min(10)])
+            .style(style)
+            .block(
+                Block::default()
+                    .title(format!("{} ({})", STATISTICS_PANEL_TITLE, scope))
+                    .borders(Borders::LEFT),
+            );
+        f.render_widget(table, rect);
+    }
+
+    /// Render spectral peak panel.
+    ///
+    /// This function lists the detected peaks with their interpolated frequency,
+    /// amplitude and harmonic order.
+    ///
+    /// ---
+    ///
+    /// * `f`: The frame to render the panel on.
+    /// * `rect`: The rectangle to render the panel in.
+    /// * `peaks`: The peaks to list.
+    fn render_peaks(&self, f: &mut Frame, rect: Rect, peaks: &[SpectralPeak]) {
+        let style = Style::default().fg(self.state.canvas_style().canvas_color);
+        let rows = peaks.iter().enumerate().map(|(i, peak)| {
+            Row::new(vec![
+                (i + 1).to_string(),
+                format!("{:.3}", peak.frequency),
+                format!("{:.4}", peak.amplitude),
+                peak.harmonic
+                    .map(|order| format!("H{}", order))
+                    .unwrap_or_default(),
+            ])
+        });
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Min(10),
+                Constraint::Min(10),
+                Constraint::Length(4),
+            ],
+        )
+        .header(
+            Row::new(vec!["#", "f (Hz)", "Amplitude", ""])
+                .style(Style::default().fg(Color::Yellow)),
+        )
+        .style(style)
+        .block(
+            Block::default()
+                .title(PEAK_PANEL_TITLE)
+                .borders(Borders::LEFT),
+        );
+        f.render_widget(table, rect);
+    }
+
+    /// Render the progress of the running job.
+    ///
+    /// This function draws a single line gauge labelled with the command,
+    /// its completion and the time it has been running for.
+    ///
+    /// ---
+    ///
+    /// * `f`: The frame to render the gauge on.
+    /// * `rect`: The rectangle to render the gauge in.
+    /// * `job`: The running job.
+    fn render_progress(&self, f: &mut Frame, rect: Rect, job: &RunningJob) {
+        let fraction = job.progress.fraction();
+        let hint = if job.progress.is_cancelled() {
+            JOB_CANCELLING_HINT
+        } else {
+            JOB_CANCEL_HINT
+        };
+        let gauge = LineGauge::default()
+            .filled_style(Style::default().fg(Color::Cyan))
+            .unfilled_style(Style::default().fg(Color::DarkGray))
+            .label(format!(
+                "{} {:>3.0}% {:.1} s, {}",
+                job.name,
+                fraction * 100.0,
+                job.elapsed().as_secs_f64(),
+                hint
+            ))
+            .ratio(fraction);
+        f.render_widget(gauge, rect);
+    }
+
+    /// Render octave band chart.
+    ///
+    /// This function draws one bar per band labelled with its nominal center
+    /// frequency and level, followed by the total level bar. Bars start 1 dB
+    /// below the lowest level so every band stays visible.
+    ///
+    /// ---
+    ///
+    /// * `f`: The frame to render the bars on.
+    /// * `rect`: The rectangle to render the bars in.
+    /// * `octave`: The band levels to render.
+    fn render_octave(&self, f: &mut Frame, rect: Rect, octave: &OctaveSpectrum) {
+        let style = Style::default().fg(self.state.canvas_style().canvas_color);
+        let floor = octave
+            .bands
+            .iter()
+            .map(|band| band.level)
+            .fold(octave.total, f64::min)
+            - 1.0;
+        let bar = |label: String, level: f64, color: Color| {
+            Bar::default()
+                .value(((level - floor) * 10.0).round() as u64)
+                .label(Line::from(label))
+                .text_value(format!("{:.0}", level))
+                .style(Style::default().fg(color))
+                .value_style(Style::default().fg(Color::Black).bg(color))
+        };
+        let bars: Vec<Bar> = octave
+            .bands
+            .iter()
+            .map(|band| {
+                let label = if band.nominal >= 1000.0 {
+                    format!("{}k", band.nominal / 1000.0)
+                } else {
+                    band.nominal.to_string()
+                };
+                bar(label, band.level, Color::Cyan)
+            })
+            .chain(std::iter::once(bar(
+                String::from(OCTAVE_TOTAL_LABEL),
+                octave.total,
+                Color::Yellow,
+            )))
+            .collect();
+        let count = bars.len() as u16;
+        let width = (rect
+            .width
+            .saturating_sub(2 + OCTAVE_BAR_GAP * count.saturating_sub(1))
+            / count.max(1))
+        .max(1);
+        let chart = BarChart::default()
+            .block(
+                Block::default()
+                    .title(format!(
+                        "{} (total {:.1} dB)",
+                        OCTAVE_PANEL_TITLE, octave.total
+                    ))
+                    .borders(Borders::ALL)
+                    .style(style),
+            )
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(width)
+            .bar_gap(OCTAVE_BAR_GAP)
+            .label_style(style);
+        f.render_widget(chart, rect);
+    }
+
+    /// Render heatmap chart.
+    ///
+    /// This function paints the heatmap cells with the selected colormap,
+    /// two vertical cells per terminal character, and draws axis labels
+    /// and a color-scale legend next to it.
+    ///
+    /// ---
+    ///
+    /// * `f`: The frame to render the heatmap on.
+    /// * `rect`: The rectangle to render the heatmap in.
+    /// * `heatmap`: The heatmap to render.
+    fn render_heatmap(&self, f: &mut Frame, rect: Rect, heatmap: &Heatmap) {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(1), Constraint::Length(HEATMAP_LEGEND_WIDTH)])
+            .split(rect);
+        let (plot, legend) = (chunks[0], chunks[1]);
+        let style = self.state.canvas_style();
+        let (x_min, x_max) = (self.state.x_min(), self.state.x_max());
+        let (y_min, y_max) = (self.state.y_min(), self.state.y_max());
+        let value_max = heatmap.max_value();
+        let value_min = value_max - style.dynamic_range;
+        let color_of = |x: f64, y: f64| {
+            heatmap
+                .value_at(x, y)
+                .map(|v| style.colormap.color((v - value_min) / style.dynamic_range))
+                .unwrap_or(Color::Reset)
+        };
+
+        let buf = f.buffer_mut();
+        let half_rows = plot.height as f64 * 2.0;
+        for row in 0..plot.height {
+            let upper = y_max - (row as f64 * 2.0 + 0.5) / half_rows * (y_max - y_min);
+            let lower = y_max - (row as f64 * 2.0 + 1.5) / half_rows * (y_max - y_min);
+            for col in 0..plot.width {
+                let x = x_min + (col as f64 + 0.5) / plot.width as f64 * (x_max - x_min);
+                if let Some(cell) = buf.cell_mut((plot.x + col, plot.y + row)) {
+                    cell.set_symbol(HEATMAP_CELL_SYMBOL)
+                        .set_fg(color_of(x, upper))
+                        .set_bg(color_of(x, lower));
+                    let boundary = heatmap.boundary_at(x);
+                    if boundary.is_some_and(|y| y <= upper && y > lower) {
+                        cell.set_symbol(HEATMAP_BOUNDARY_SYMBOL)
+                            .set_fg(style.canvas_color);
+                    }
+                }
+            }
+        }
+
+        let label_style = Style::default().fg(style.canvas_color);
+        let steps = style.canvas_steps / 4;
+        (1..steps).for_each(|i| {
+            let col = plot.width as u32 * i / steps;
+            let val = x_min + (x_max - x_min) * col as f64 / plot.width as f64;
+            buf.set_string(
+                plot.x + col as u16,
+                plot.bottom().saturating_sub(1),
+                format!("{:.4}", val),
+                label_style,
+            );
+            let row = plot.height as u32 * i / steps;
+            let val = y_max - (y_max - y_min) * row as f64 / plot.height as f64;
+            buf.set_string(
+                plot.x,
+                plot.y + row as u16,
+                format!("{:.1}", val),
+                label_style,
+            );
+        });
+
+        if legend.height < 3 {
+            return;
+        }
+        buf.set_string(
+            legend.x + 1,
+            legend.y,
+            format!("{:.1}", value_max),
+            label_style,
+        );
+        buf.set_string(
+            legend.x + 1,
+            legend.bottom() - 2,
+            format!("{:.1}", value_min),
+            label_style,
+        );
+        buf.set_string(
+            legend.x + 1,
+            legend.bottom() - 1,
+            &heatmap.unit,
+            label_style,
+        );
+        let bar_height = legend.height - 3;
+        for row in 0..bar_height {
+            let t = 1.0 - row as f64 / (bar_height.max(2) - 1) as f64;
+            buf.set_string(
+                legend.x + 1,
+                legend.y + 1 + row,
+                "  ",
+                Style::default().bg(style.colormap.color(t)),
+            );
+        }
+    }
 }
 
 impl Component for ChartViewComponent {
@@ -114,20 +501,108 @@ impl Component for ChartViewComponent {
     }
 
     fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let rect = match self.worker.status() {
+            Some(job) => {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(1), Constraint::Length(JOB_PROGRESS_HEIGHT)])
+                    .split(rect);
+                self.render_progress(f, chunks[1], job);
+                chunks[0]
+            }
+            None => rect,
+        };
         let Some(current_dataset) = &self.app_state.borrow().get_current_chart() else {
             self.state.set_current_chart(None);
             return;
         };
         self.state.set_current_chart(Some(current_dataset.clone()));
-        let current_dataset_borrow = current_dataset.borrow();
+        let current_dataset_borrow = current_dataset.read();
+        if let Some(heatmap) = &current_dataset_borrow.heatmap {
+            self.render_heatmap(f, rect, heatmap);
+            return;
+        }
+        if let Some(octave) = &current_dataset_borrow.octave {
+            self.render_octave(f, rect, octave);
+            return;
+        }
+        let scope = self.state.statistics_scope();
+        let show_peaks = !current_dataset_borrow.peaks.is_empty();
+        let rect = if scope == StatisticsScope::Off && !show_peaks {
+            rect
+        } else {
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(1), Constraint::Length(SIDE_PANEL_WIDTH)])
+                .split(rect);
+            let mut constraints = Vec::new();
+            if scope != StatisticsScope::Off {
+                constraints.push(Constraint::Length(STATISTICS_PANEL_HEIGHT));
+            }
+            if show_peaks {
+                constraints.push(Constraint::Min(1));
+            }
+            let panels = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(constraints)
+                .split(chunks[1]);
+            if scope != StatisticsScope::Off {
+                let range = (scope == StatisticsScope::View)
+                    .then(|| (self.state.x_min(), self.state.x_max()));
+                let statistics = self.state.statistics(current_dataset, range, || {
+                    self.service.statistics(&current_dataset_borrow, range)
+                });
+                self.render_statistics(f, panels[0], scope, statistics);
+            }
+            if show_peaks {
+                self.render_peaks(f, panels[panels.len() - 1], &current_dataset_borrow.peaks);
+            }
+            chunks[0]
+        };
         let pure_coordinates = current_dataset_borrow.data_to_pure_coordinates();
-        let datasets = vec![
+        let overlay_coordinates: Vec<(f64, f64)> = current_dataset_borrow
+            .overlay
+            .iter()
+            .flat_map(|overlay| overlay.iter().map(|p| (p.x, p.y)))
+            .collect();
+        let band_coordinates: Vec<Vec<(f64, f64)>> = current_dataset_borrow
+            .band
+            .iter()
+            .flat_map(|band| [&band.lower, &band.upper])
+            .map(|points| points.iter().map(|p| (p.x, p.y)).collect())
+            .collect();
+        let peak_coordinates: Vec<(f64, f64)> = current_dataset_borrow
+            .peaks
+            .iter()
+            .map(|peak| (peak.frequency, peak.amplitude))
+            .collect();
+        let mut datasets: Vec<Dataset> = band_coordinates
+            .iter()
+            .map(|coordinates| {
+                Dataset::default()
+                    .marker(symbols::Marker::Braille)
+                    .style(Style::default().fg(Color::Gray))
+                    .graph_type(GraphType::Line)
+                    .data(coordinates)
+            })
+            .collect();
+        datasets.extend([
+            Dataset::default()
+                .marker(symbols::Marker::HalfBlock)
+                .style(Style::default().fg(Color::DarkGray))
+                .graph_type(current_dataset_borrow.metadata.chart_display_type)
+                .data(&overlay_coordinates),
             Dataset::default()
                 .marker(symbols::Marker::HalfBlock)
                 .style(Style::default().fg(Color::Cyan))
                 .graph_type(current_dataset_borrow.metadata.chart_display_type)
                 .data(&pure_coordinates),
-        ];
+            Dataset::default()
+                .marker(symbols::Marker::Block)
+                .style(Style::default().fg(Color::Yellow))
+                .graph_type(GraphType::Scatter)
+                .data(&peak_coordinates),
+        ]);
 
         let chart = Chart::new(datasets)
             .block(Block::default().borders(Borders::NONE))
@@ -160,189 +635,145 @@ impl Component for ChartViewComponent {
             return Ok(());
         };
         match command {
-            ChartViewCommands::ZoomIn => {
-                let Some(multiplier_arg) = args.get(1) else {
-                    return Err(CommandError::NotEnoughArguments.into());
-                };
-                let multiplier_arg = multiplier_arg
-                    .parse::<f64>()
-                    .map_err(|_| CommandError::InvalidArguments(String::from(*multiplier_arg)))?;
-                self.state.chart_scale(false, multiplier_arg);
+            ChartViewCommands::View(ViewCommands::ZoomIn) => {
+                let multiplier: f64 = required_arg(&args, 1)?;
+                self.state.chart_scale(false, multiplier);
             }
-            ChartViewCommands::ZoomOut => {
-                let Some(multiplier_arg) = args.get(1) else {
-                    return Err(CommandError::NotEnoughArguments.into());
-                };
-                let multiplier_arg = multiplier_arg
-                    .parse::<f64>()
-                    .map_err(|_| CommandError::InvalidArguments(String::from(*multiplier_arg)))?;
-                self.state.chart_scale(true, multiplier_arg);
+            ChartViewCommands::View(ViewCommands::ZoomOut) => {
+                let multiplier: f64 = required_arg(&args, 1)?;
+                self.state.chart_scale(true, multiplier);
             }
-            ChartViewCommands::MoveLeft => {
-                let Some(points_arg) = args.get(1) else {
-                    return Err(CommandError::NotEnoughArguments.into());
-                };
-                let points_arg = points_arg
-                    .parse::<f64>()
-                    .map_err(|_| CommandError::InvalidArguments(String::from(*points_arg)))?;
-                self.state.chart_move(true, points_arg);
+            ChartViewCommands::View(ViewCommands::MoveLeft) => {
+                let points: f64 = required_arg(&args, 1)?;
+                self.state.chart_move(true, points);
             }
-            ChartViewCommands::MoveRight => {
-                let Some(points_arg) = args.get(1) else {
-                    return Err(CommandError::NotEnoughArguments.into());
-                };
-                let points_arg = points_arg
-                    .parse::<f64>()
-                    .map_err(|_| CommandError::InvalidArguments(String::from(*points_arg)))?;
-                self.state.chart_move(false, points_arg);
+            ChartViewCommands::View(ViewCommands::MoveRight) => {
+                let points: f64 = required_arg(&args, 1)?;
+                self.state.chart_move(false, points);
             }
-            ChartViewCommands::FastFourierTransform => {
+            ChartViewCommands::View(ViewCommands::ClearPeaks) => {
                 let Some(current_chart) = self.state.current_chart() else {
                     return Err(CommandError::NoChart.into());
                 };
-                let current_chart_borrow = current_chart.borrow();
-                let chart = ChartModel::new(
-                    self.service.fft_forward(&current_chart_borrow)?,
-                    current_chart_borrow.metadata.chart_display_type,
-                    current_chart_borrow.sample_rate,
-                    &current_chart_borrow.metadata.title,
-                    Some(ChartTransform::Fft),
-                );
-                state_borrow.add_chart(chart);
+                current_chart.write().peaks.clear();
             }
-            ChartViewCommands::ShortTimeFourierTransform => {
-                let (Some(window_size), Some(hop_size)) = (args.get(1), args.get(2)) else {
-                    return Err(CommandError::NotEnoughArguments.into());
-                };
-                let Some(current_chart) = self.state.current_chart() else {
-                    return Err(CommandError::NoChart.into());
-                };
-                let current_chart_borrow = current_chart.borrow();
-                let window_size = window_size
-                    .parse::<usize>()
-                    .map_err(|_| CommandError::InvalidArguments(String::from(*window_size)))?;
-                let hop_size = hop_size
-                    .parse::<usize>()
-                    .map_err(|_| CommandError::InvalidArguments(String::from(*hop_size)))?;
-                let chart = ChartModel::new(
-                    self.service
-                        .stft_forward(&current_chart_borrow, window_size, hop_size)?,
-                    current_chart_borrow.metadata.chart_display_type,
-                    current_chart_borrow.sample_rate,
-                    &current_chart_borrow.metadata.title,
-                    Some(ChartTransform::Stft),
-                );
-                state_borrow.add_chart(chart);
+            ChartViewCommands::View(ViewCommands::Statistics) => {
+                let scope: StatisticsScope = optional_arg(&args, 1, StatisticsScope::View)?;
+                self.state.set_statistics_scope(scope);
             }
-            ChartViewCommands::FftFilterLowPass => {
-                let Some(arg) = args.get(1) else {
-                    return Err(CommandError::NotEnoughArguments.into());
-                };
-                let filter = FftFilterType::LowPass(
-                    arg.parse::<f64>()
-                        .map_err(|_| CommandError::InvalidArguments(String::from(*arg)))?,
-                );
+            ChartViewCommands::View(ViewCommands::Unit) => {
+                let unit: SignalUnit = required_arg(&args, 1)?;
                 let Some(current_chart) = self.state.current_chart() else {
                     return Err(CommandError::NoChart.into());
                 };
-                let current_chart_borrow = current_chart.borrow();
-                let chart = ChartModel::new(
-                    self.service
-                        .apply_fft_filter(&current_chart_borrow, filter)?,
-                    current_chart_borrow.metadata.chart_display_type,
-                    current_chart_borrow.sample_rate,
-                    &current_chart_borrow.metadata.title,
-                    Some(ChartTransform::Filtered),
-                );
-                state_borrow.add_chart(chart);
+                current_chart.write().metadata.unit = Some(unit);
             }
-            ChartViewCommands::FftFilterHighPass => {
-                let Some(arg) = args.get(1) else {
-                    return Err(CommandError::NotEnoughArguments.into());
-                };
-                let Some(current_chart) = self.state.current_chart() else {
-                    return Err(CommandError::NoChart.into());
-                };
-                let filter = FftFilterType::HighPass(
-                    arg.parse::<f64>()
-                        .map_err(|_| CommandError::InvalidArguments(String::from(*arg)))?,
-                );
-                let current_chart_borrow = current_chart.borrow();
-                let chart = ChartModel::new(
-                    self.service
-                        .apply_fft_filter(&current_chart_borrow, filter)?,
-                    current_chart_borrow.metadata.chart_display_type,
-                    current_chart_borrow.sample_rate,
-                    &current_chart_borrow.metadata.title,
-                    Some(ChartTransform::Filtered),
-                );
-                state_borrow.add_chart(chart);
+            ChartViewCommands::View(ViewCommands::Colormap) => {
+                let colormap: Colormap = required_arg(&args, 1)?;
+                self.state.canvas_style_as_mut().colormap = colormap;
             }
-            ChartViewCommands::FftFilterBandPass => {
-                let (Some(low_band), Some(high_band)) = (args.get(1), args.get(2)) else {
-                    return Err(CommandError::NotEnoughArguments.into());
-                };
-                let Some(current_chart) = self.state.current_chart() else {
-                    return Err(CommandError::NoChart.into());
-                };
-                let filter = FftFilterType::BandPass(
-                    low_band
-                        .parse::<f64>()
-                        .map_err(|_| CommandError::InvalidArguments(String::from(*low_band)))?,
-                    high_band
-                        .parse::<f64>()
-                        .map_err(|_| CommandError::InvalidArguments(String::from(*high_band)))?,
-                );
-                let current_chart_borrow = current_chart.borrow();
-                let chart = ChartModel::new(
-                    self.service
-                        .apply_fft_filter(&current_chart_borrow, filter)?,
-                    current_chart_borrow.metadata.chart_display_type,
-                    current_chart_borrow.sample_rate,
-                    &current_chart_borrow.metadata.title,
-                    Some(ChartTransform::Filtered),
-                );
-                state_borrow.add_chart(chart);
+            ChartViewCommands::View(ViewCommands::DynamicRange) => {
+                let dynamic_range: f64 = required_arg(&args, 1)?;
+                if dynamic_range <= 0.0 {
+                    return Err(CommandError::InvalidArguments(String::from(args[1])).into());
+                }
+                self.state.canvas_style_as_mut().dynamic_range = dynamic_range;
             }
-            ChartViewCommands::FftFilterBandStop => {
-                let (Some(low_band), Some(high_band)) = (args.get(1), args.get(2)) else {
-                    return Err(CommandError::NotEnoughArguments.into());
-                };
+            ChartViewCommands::View(ViewCommands::Export) => {
+                let path: String = required_arg(&args, 1)?;
                 let Some(current_chart) = self.state.current_chart() else {
                     return Err(CommandError::NoChart.into());
                 };
-                let filter = FftFilterType::BandStop(
-                    low_band
-                        .parse::<f64>()
-                        .map_err(|_| CommandError::InvalidArguments(String::from(*low_band)))?,
-                    high_band
-                        .parse::<f64>()
-                        .map_err(|_| CommandError::InvalidArguments(String::from(*high_band)))?,
-                );
-                let current_chart_borrow = current_chart.borrow();
-                let chart = ChartModel::new(
-                    self.service
-                        .apply_fft_filter(&current_chart_borrow, filter)?,
-                    current_chart_borrow.metadata.chart_display_type,
-                    current_chart_borrow.sample_rate,
-                    &current_chart_borrow.metadata.title,
-                    Some(ChartTransform::Filtered),
-                );
-                state_borrow.add_chart(chart);
+                self.export_chart(Path::new(&path), &current_chart.read())?;
             }
-            ChartViewCommands::HaarWaveletTransform => {
-                let Some(current_chart) = self.state.current_chart() else {
-                    return Err(CommandError::NoChart.into());
+            ChartViewCommands::View(ViewCommands::Pipeline) => {
+                let action: PipelineAction = required_arg(&args, 1)?;
+                match action {
+                    PipelineAction::Define => {
+                        let name: String = required_arg(&args, 2)?;
+                        self.state
+                            .add_pipeline(Pipeline::new(&name, &args[3..].join(" "))?);
+                    }
+                    PipelineAction::Run => {
+                        let name: String = required_arg(&args, 2)?;
+                        let Some(pipeline) = self.state.pipeline(&name).cloned() else {
+                            return Err(PipelineError::UnknownPipeline(name).into());
+                        };
+                        let Some(current_chart) = self.state.current_chart() else {
+                            return Err(CommandError::NoChart.into());
+                        };
+                        let charts = state_borrow.charts();
+                        self.worker.spawn(
+                            &format!("{} {}", args[0], name),
+                            Box::new(move |service| {
+                                pipeline::run_pipeline(
+                                    &pipeline,
+                                    current_chart.snapshot(),
+                                    &charts,
+                                    service,
+                                )
+                            }),
+                        )?;
+                    }
+                    PipelineAction::Batch => {
+                        let name: String = required_arg(&args, 2)?;
+                        let Some(pipeline) = self.state.pipeline(&name).cloned() else {
+                            return Err(PipelineError::UnknownPipeline(name).into());
+                        };
+                        // At least one path is required
+                        let paths = (3..args.len().max(4))
+                            .map(|index| path_arg(&args, index))
+                            .collect::<Result<Vec<PathBuf>, CommandError>>()?;
+                        let parsers = self.file_parsers.clone();
+                        let charts = state_borrow.charts();
+                        self.worker.spawn(
+                            &format!("{} {}", args[0], name),
+                            Box::new(move |service| {
+                                pipeline::run_batch(&pipeline, &paths, &parsers, &charts, service)
+                            }),
+                        )?;
+                    }
+                    PipelineAction::Save => {
+                        let path: String = required_arg(&args, 2)?;
+                        let mode: SaveMode = optional_arg(&args, 3, SaveMode::New)?;
+                        if mode == SaveMode::New && Path::new(&path).exists() {
+                            return Err(PipelineError::FileExists(path).into());
+                        }
+                        let contents: String = self
+                            .state
+                            .pipelines()
+                            .map(|pipeline| format!("{}\n", pipeline))
+                            .collect();
+                        fs::write(path, contents)?;
+                    }
+                    PipelineAction::Load => {
+                        let path = path_arg(&args, 2)?;
+                        let pipelines = fs::read_to_string(path)?
+                            .lines()
+                            .map(str::trim)
+                            .filter(|line| {
+                                !line.is_empty() && !line.starts_with(PIPELINE_COMMENT_PREFIX)
+                            })
+                            .map(Pipeline::from_str)
+                            .collect::<Result<Vec<_>>>()?;
+                        for pipeline in pipelines {
+                            self.state.add_pipeline(pipeline);
+                        }
+                    }
+                }
+            }
+            ChartViewCommands::Processing(command) => {
+                let request = ProcessingRequest {
+                    command,
+                    cmd: cmd.clone(),
+                    current: self.state.current_chart(),
+                    charts: state_borrow.charts(),
+                    view: (self.state.x_min(), self.state.x_max()),
                 };
-                let current_chart_borrow = current_chart.borrow();
-                let chart = ChartModel::new(
-                    self.service.haar_wavelet_transform(&current_chart_borrow)?,
-                    current_chart_borrow.metadata.chart_display_type,
-                    current_chart_borrow.sample_rate,
-                    &current_chart_borrow.metadata.title,
-                    Some(ChartTransform::WaveletHaar),
-                );
-                state_borrow.add_chart(chart);
+                self.worker.spawn(
+                    args[0],
+                    Box::new(move |service| handlers::process(&request, service)),
+                )?;
             }
         };
         state_borrow.set_command(None);
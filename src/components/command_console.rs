@@ -75,25 +75,17 @@ impl Component for CommandConsoleComponent {
                 state.flush_input();
                 self.app_state.borrow_mut().to_input_mode();
             }
-            KeyCode::Char(c) => {
-                if !is_error {
-                    state.push_char(c);
-                }
+            KeyCode::Char(c) if !is_error => {
+                state.push_char(c);
             }
-            KeyCode::Backspace => {
-                if !is_error {
-                    state.remove_char();
-                }
+            KeyCode::Backspace if !is_error => {
+                state.remove_char();
             }
-            KeyCode::Left => {
-                if !is_error {
-                    state.input_cursor_move(true);
-                }
+            KeyCode::Left if !is_error => {
+                state.input_cursor_move(true);
             }
-            KeyCode::Right => {
-                if !is_error {
-                    state.input_cursor_move(false);
-                }
+            KeyCode::Right if !is_error => {
+                state.input_cursor_move(false);
             }
             KeyCode::Up => {
                 state.clear_error();
@@ -1,10 +1,15 @@
 use ratatui::style::Color;
 
-use crate::shared::constants::general::DEFAULT_COLOR;
+use crate::{
+    models::chart_view::colormap::Colormap,
+    shared::constants::{chart_view::DEFAULT_DYNAMIC_RANGE, general::DEFAULT_COLOR},
+};
 
 pub struct ChartViewStyle {
     pub canvas_color: Color,
     pub canvas_steps: u32,
+    pub colormap: Colormap,
+    pub dynamic_range: f64,
 }
 
 impl Default for ChartViewStyle {
@@ -18,6 +23,8 @@ impl ChartViewStyle {
         Self {
             canvas_color: DEFAULT_COLOR,
             canvas_steps: 17,
+            colormap: Colormap::default(),
+            dynamic_range: DEFAULT_DYNAMIC_RANGE,
         }
     }
 }
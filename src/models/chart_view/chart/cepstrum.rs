@@ -0,0 +1,23 @@
+#[derive(Debug, Clone, Default)]
+pub struct Cepstrum {
+    pub coefficients: Vec<f64>,
+    pub sample_rate: f32,
+}
+
+impl Cepstrum {
+    pub fn new(coefficients: Vec<f64>, sample_rate: f32) -> Self {
+        Self {
+            coefficients,
+            sample_rate,
+        }
+    }
+
+    /// Quefrency of a coefficient in seconds
+    ///
+    /// The real cepstrum is even, so the upper half of the coefficients
+    /// mirrors the lower half and maps onto the same quefrencies.
+    pub fn quefrency(&self, index: usize) -> f64 {
+        let n = self.coefficients.len();
+        index.min(n - index) as f64 / self.sample_rate as f64
+    }
+}
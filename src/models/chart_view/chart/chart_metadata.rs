@@ -1,4 +1,4 @@
-use crate::models::chart_view::chart::chart_transform::ChartTransform;
+use crate::models::chart_view::chart::{chart_transform::ChartTransform, signal_unit::SignalUnit};
 use ratatui::widgets::GraphType;
 
 #[derive(Debug, Clone, Default)]
@@ -6,6 +6,9 @@ pub struct ChartMetadata {
     pub title: String,
     pub transform: ChartTransform,
     pub chart_display_type: GraphType,
+    pub details: Option<String>,
+    pub unit: Option<SignalUnit>,
+    pub per_unit: Option<SignalUnit>,
 }
 
 impl ChartMetadata {
@@ -18,10 +21,60 @@ impl ChartMetadata {
             title: String::from(title),
             transform: transform.unwrap_or_default(),
             chart_display_type,
+            details: None,
+            unit: None,
+            per_unit: None,
+        }
+    }
+
+    /// Unit of the chart values
+    ///
+    /// Derived from the unit of the signal the chart was computed from,
+    /// divided by `per_unit` for responses relative to another signal.
+    /// Densities are given per Hz of the squared unit and levels in dB
+    /// relative to one unit, while angles, coherences and cepstra carry
+    /// no signal unit.
+    pub fn value_unit(&self) -> Option<String> {
+        // Compound units are parenthesized before they are combined
+        let grouped = |unit: String| {
+            if unit.contains('/') {
+                format!("({})", unit)
+            } else {
+                unit
+            }
+        };
+        let unit = self.unit.map(|unit| match self.per_unit {
+            Some(per_unit) => format!(
+                "{}/{}",
+                grouped(unit.to_string()),
+                grouped(per_unit.to_string())
+            ),
+            None => unit.to_string(),
+        });
+        match self.transform {
+            ChartTransform::Psd => Some(match unit {
+                Some(unit) => format!("{}²/Hz", grouped(unit)),
+                None => String::from("unit²/Hz"),
+            }),
+            ChartTransform::Magnitude | ChartTransform::OctaveBands => {
+                unit.map(|unit| format!("dB re 1 {}", unit))
+            }
+            ChartTransform::Phase
+            | ChartTransform::Coherence
+            | ChartTransform::RealCepstrum
+            | ChartTransform::PowerCepstrum => None,
+            _ => unit,
         }
     }
 
     pub fn description(&self) -> String {
-        self.transform.to_string()
+        let transform = match self.value_unit() {
+            Some(unit) => format!("{} [{}]", self.transform, unit),
+            None => self.transform.to_string(),
+        };
+        match &self.details {
+            Some(details) => format!("{} ({})", transform, details),
+            None => transform,
+        }
     }
 }
@@ -1,7 +1,14 @@
-use crate::models::chart_view::chart::{
-    chart_metadata::ChartMetadata, chart_transform::ChartTransform, point::Point,
+use crate::models::{
+    chart_view::chart::{
+        cepstrum::Cepstrum, chart_metadata::ChartMetadata, chart_transform::ChartTransform,
+        complex_spectrum::ComplexSpectrum, heatmap::Heatmap, octave_spectrum::OctaveSpectrum,
+        point::Point, spectral_peak::SpectralPeak, spread_band::SpreadBand,
+        stft_frames::StftFrames, wavelet::WaveletCoefficients,
+    },
+    files::signal_header::SignalHeader,
 };
 use ratatui::widgets::GraphType;
+use std::sync::Arc;
 
 const MARGIN: f64 = 0.07;
 
@@ -14,6 +21,17 @@ pub struct ChartModel {
     pub sample_rate: f32,
     pub data: Vec<Point>,
     pub metadata: ChartMetadata,
+    pub spectrum: Option<ComplexSpectrum>,
+    pub heatmap: Option<Heatmap>,
+    pub stft: Option<StftFrames>,
+    pub cepstrum: Option<Cepstrum>,
+    pub wavelet: Option<WaveletCoefficients>,
+    pub origin: Option<Arc<Vec<f64>>>,
+    pub overlay: Option<Arc<Vec<Point>>>,
+    pub band: Option<SpreadBand>,
+    pub octave: Option<OctaveSpectrum>,
+    pub peaks: Vec<SpectralPeak>,
+    pub header: Option<SignalHeader>,
 }
 
 impl ChartModel {
@@ -55,9 +73,56 @@ impl ChartModel {
             data,
             sample_rate,
             metadata: ChartMetadata::new(title, transform, chart_display_type),
+            spectrum: None,
+            heatmap: None,
+            stft: None,
+            cepstrum: None,
+            wavelet: None,
+            origin: None,
+            overlay: None,
+            band: None,
+            octave: None,
+            peaks: Vec::new(),
+            header: None,
         }
     }
 
+    /// Create a heatmap chart
+    ///
+    /// The chart bounds are taken from the heatmap axes and the chart keeps no points.
+    pub fn from_heatmap(
+        heatmap: Heatmap,
+        sample_rate: f32,
+        title: &str,
+        transform: Option<ChartTransform>,
+    ) -> Self {
+        Self {
+            x_min: heatmap.x.first().copied().unwrap_or_default(),
+            x_max: heatmap.x.last().copied().unwrap_or_default(),
+            y_min: heatmap.y.first().copied().unwrap_or_default(),
+            y_max: heatmap.y.last().copied().unwrap_or_default(),
+            sample_rate,
+            data: Vec::new(),
+            metadata: ChartMetadata::new(title, transform, GraphType::Line),
+            spectrum: None,
+            heatmap: Some(heatmap),
+            stft: None,
+            cepstrum: None,
+            wavelet: None,
+            origin: None,
+            overlay: None,
+            band: None,
+            octave: None,
+            peaks: Vec::new(),
+            header: None,
+        }
+    }
+
+    /// Shared copy of the chart values
+    pub fn samples(&self) -> Arc<Vec<f64>> {
+        Arc::new(self.data.iter().map(|p| p.y).collect())
+    }
+
     pub fn data_to_pure_coordinates(&self) -> Vec<(f64, f64)> {
         self.data
             .iter()
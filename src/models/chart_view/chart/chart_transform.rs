@@ -1,8 +1,7 @@
 use strum_macros::Display;
 use strum_macros::{AsRefStr, EnumString};
 
-#[derive(Debug, EnumString, AsRefStr, PartialEq, Hash, Eq, Clone, Copy, Display)]
-#[derive(Default)]
+#[derive(Debug, EnumString, AsRefStr, PartialEq, Hash, Eq, Clone, Copy, Display, Default)]
 pub enum ChartTransform {
     #[strum(serialize = "Standard")]
     #[default]
@@ -14,10 +13,117 @@ pub enum ChartTransform {
     #[strum(serialize = "STFT")]
     Stft,
 
-    #[strum(serialize = "Haar")]
-    WaveletHaar,
+    #[strum(serialize = "DWT detail")]
+    WaveletDetail,
+
+    #[strum(serialize = "DWT approximation")]
+    WaveletApproximation,
+
+    #[strum(serialize = "CWT")]
+    Cwt,
+
+    #[strum(serialize = "Envelope")]
+    Envelope,
+
+    #[strum(serialize = "Inst. phase")]
+    InstantaneousPhase,
+
+    #[strum(serialize = "Inst. frequency")]
+    InstantaneousFrequency,
+
+    #[strum(serialize = "Envelope spectrum")]
+    EnvelopeSpectrum,
+
+    #[strum(serialize = "Autocorrelation")]
+    Autocorrelation,
+
+    #[strum(serialize = "Cross-correlation")]
+    CrossCorrelation,
+
+    #[strum(serialize = "Coherence")]
+    Coherence,
+
+    #[strum(serialize = "Real cepstrum")]
+    RealCepstrum,
+
+    #[strum(serialize = "Power cepstrum")]
+    PowerCepstrum,
+
+    #[strum(serialize = "Liftered spectrum")]
+    Liftered,
+
+    #[strum(serialize = "Detrended")]
+    Detrended,
+
+    #[strum(serialize = "Smoothed")]
+    Smoothed,
+
+    #[strum(serialize = "Despiked")]
+    Despiked,
+
+    #[strum(serialize = "Declipped")]
+    Declipped,
+
+    #[strum(serialize = "Non-uniform")]
+    NonUniform,
+
+    #[strum(serialize = "Integrated")]
+    Integrated,
+
+    #[strum(serialize = "Differentiated")]
+    Differentiated,
 
     #[strum(serialize = "Filtered")]
     Filtered,
+
+    #[strum(serialize = "Phase")]
+    Phase,
+
+    #[strum(serialize = "Real")]
+    Real,
+
+    #[strum(serialize = "Imaginary")]
+    Imaginary,
+
+    #[strum(serialize = "Magnitude (dB)")]
+    Magnitude,
+
+    #[strum(serialize = "PSD")]
+    Psd,
+
+    #[strum(serialize = "Block Average")]
+    BlockAverage,
+
+    #[strum(serialize = "Octave Bands (dB)")]
+    OctaveBands,
 }
 
+impl ChartTransform {
+    /// Whether the chart holds time domain samples that can be analysed further.
+    pub fn is_time_domain(&self) -> bool {
+        matches!(
+            self,
+            ChartTransform::Standard
+                | ChartTransform::Detrended
+                | ChartTransform::Smoothed
+                | ChartTransform::Despiked
+                | ChartTransform::Declipped
+                | ChartTransform::Integrated
+                | ChartTransform::Differentiated
+        )
+    }
+
+    /// Whether the chart holds a spectrum suitable for peak detection.
+    pub fn is_spectrum(&self) -> bool {
+        matches!(
+            self,
+            ChartTransform::Fft
+                | ChartTransform::Filtered
+                | ChartTransform::Liftered
+                | ChartTransform::Psd
+                | ChartTransform::BlockAverage
+                | ChartTransform::EnvelopeSpectrum
+                | ChartTransform::Magnitude
+        )
+    }
+}
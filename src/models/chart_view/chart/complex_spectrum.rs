@@ -0,0 +1,36 @@
+use rustfft::num_complex::Complex;
+
+#[derive(Debug, Clone, Default)]
+pub struct ComplexSpectrum {
+    pub bins: Vec<Complex<f64>>,
+    pub sample_rate: f32,
+    pub start: f64,
+}
+
+impl ComplexSpectrum {
+    pub fn new(bins: Vec<Complex<f64>>, sample_rate: f32, start: f64) -> Self {
+        Self {
+            bins,
+            sample_rate,
+            start,
+        }
+    }
+
+    pub fn frequency_resolution(&self) -> f64 {
+        self.sample_rate as f64 / self.bins.len() as f64
+    }
+
+    /// Positive frequency half of the spectrum
+    ///
+    /// Returns the non-negative frequency bins (Nyquist excluded) as
+    /// `(frequency, value)` pairs, scaled to the single-sided amplitude.
+    pub fn one_sided(&self) -> Vec<(f64, Complex<f64>)> {
+        let n = self.bins.len();
+        let freq_res = self.frequency_resolution();
+        self.bins[..n.div_ceil(2)]
+            .iter()
+            .enumerate()
+            .map(|(k, c)| (k as f64 * freq_res, c / n as f64 * 2.0))
+            .collect()
+    }
+}
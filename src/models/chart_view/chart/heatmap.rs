@@ -0,0 +1,88 @@
+use crate::models::chart_view::chart::point::Point;
+
+#[derive(Debug, Clone, Default)]
+pub struct Heatmap {
+    pub x: Vec<f64>,
+    pub y: Vec<f64>,
+    pub values: Vec<Vec<f64>>,
+    pub unit: String,
+    pub boundary: Vec<Point>,
+}
+
+impl Heatmap {
+    /// Create a new heatmap
+    ///
+    /// ---
+    ///
+    /// * `x`: Ascending coordinates of the columns.
+    /// * `y`: Ascending coordinates of the rows.
+    /// * `values`: Cell values indexed as `values[column][row]`.
+    /// * `unit`: The unit of the cell values shown in the legend.
+    pub fn new(x: Vec<f64>, y: Vec<f64>, values: Vec<Vec<f64>>, unit: &str) -> Self {
+        Self {
+            x,
+            y,
+            values,
+            unit: String::from(unit),
+            boundary: Vec::new(),
+        }
+    }
+
+    /// Boundary line value at the given x coordinate
+    ///
+    /// Linearly interpolates the boundary points, e.g. the cone of influence
+    /// of a scalogram. Returns `None` outside of the boundary.
+    pub fn boundary_at(&self, x: f64) -> Option<f64> {
+        let index = self.boundary.partition_point(|p| p.x < x);
+        let (left, right) = (
+            self.boundary.get(index.checked_sub(1)?)?,
+            self.boundary.get(index)?,
+        );
+        let t = (x - left.x) / (right.x - left.x);
+        Some(left.y + (right.y - left.y) * t)
+    }
+
+    /// Value of the cell nearest to the given coordinates
+    ///
+    /// Returns `None` outside of the heatmap axes.
+    pub fn value_at(&self, x: f64, y: f64) -> Option<f64> {
+        if !Self::covers(&self.x, x) || !Self::covers(&self.y, y) {
+            return None;
+        }
+        let column = Self::nearest(&self.x, x)?;
+        let row = Self::nearest(&self.y, y)?;
+        self.values.get(column)?.get(row).copied()
+    }
+
+    pub fn max_value(&self) -> f64 {
+        self.values
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|v| v.is_finite())
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    fn covers(axis: &[f64], value: f64) -> bool {
+        match (axis.first(), axis.last()) {
+            (Some(first), Some(last)) => value >= *first && value <= *last,
+            _ => false,
+        }
+    }
+
+    fn nearest(axis: &[f64], value: f64) -> Option<usize> {
+        if axis.is_empty() {
+            return None;
+        }
+        let index = axis.partition_point(|v| *v < value);
+        if index == 0 {
+            Some(0)
+        } else if index == axis.len() {
+            Some(axis.len() - 1)
+        } else if value - axis[index - 1] < axis[index] - value {
+            Some(index - 1)
+        } else {
+            Some(index)
+        }
+    }
+}
@@ -1,4 +1,15 @@
+pub mod cepstrum;
 pub mod chart_metadata;
 pub mod chart_model;
 pub mod chart_transform;
+pub mod complex_spectrum;
+pub mod heatmap;
+pub mod octave_spectrum;
 pub mod point;
+pub mod shared_chart;
+pub mod signal_statistics;
+pub mod signal_unit;
+pub mod spectral_peak;
+pub mod spread_band;
+pub mod stft_frames;
+pub mod wavelet;
@@ -0,0 +1,20 @@
+#[derive(Debug, Clone, Default)]
+pub struct OctaveBand {
+    pub nominal: f64,
+    pub center: f64,
+    pub lower: f64,
+    pub upper: f64,
+    pub level: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OctaveSpectrum {
+    pub bands: Vec<OctaveBand>,
+    pub total: f64,
+}
+
+impl OctaveSpectrum {
+    pub fn new(bands: Vec<OctaveBand>, total: f64) -> Self {
+        Self { bands, total }
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Clone, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Point {
     pub x: f64,
     pub y: f64,
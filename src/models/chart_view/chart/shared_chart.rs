@@ -0,0 +1,36 @@
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::models::chart_view::chart::chart_model::ChartModel;
+
+/// Chart handle shared between the UI and the processing worker.
+#[derive(Clone, Default)]
+pub struct SharedChart(Arc<RwLock<ChartModel>>);
+
+impl SharedChart {
+    pub fn new(chart: ChartModel) -> Self {
+        Self(Arc::new(RwLock::new(chart)))
+    }
+
+    /// Lock the chart for reading.
+    ///
+    /// A panic in another holder of the lock does not leave the chart
+    /// half-written, so a poisoned lock is recovered.
+    pub fn read(&self) -> RwLockReadGuard<'_, ChartModel> {
+        self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Lock the chart for writing.
+    pub fn write(&self) -> RwLockWriteGuard<'_, ChartModel> {
+        self.0.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Copy of the chart, so long jobs do not hold the lock while running.
+    pub fn snapshot(&self) -> ChartModel {
+        self.read().clone()
+    }
+
+    /// Whether both handles point to the same chart.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
@@ -0,0 +1,14 @@
+#[derive(Debug, Clone, Default)]
+pub struct SignalStatistics {
+    pub count: usize,
+    pub mean: f64,
+    pub rms: f64,
+    pub std: f64,
+    pub peak: f64,
+    pub peak_to_peak: f64,
+    pub crest_factor: f64,
+    pub kurtosis: f64,
+    pub skewness: f64,
+    pub header_max: Option<f32>,
+    pub header_min: Option<f32>,
+}
@@ -0,0 +1,57 @@
+use strum_macros::{Display, EnumString};
+
+const STANDARD_GRAVITY: f64 = 9.80665;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum SignalUnit {
+    #[strum(serialize = "g")]
+    Gravity,
+    #[strum(serialize = "m/s²", serialize = "m/s2")]
+    MetersPerSecondSquared,
+    #[strum(serialize = "m/s")]
+    MetersPerSecond,
+    #[strum(serialize = "mm/s")]
+    MillimetersPerSecond,
+    #[strum(serialize = "m")]
+    Meters,
+    #[strum(serialize = "mm")]
+    Millimeters,
+    #[strum(serialize = "µm", serialize = "um")]
+    Micrometers,
+}
+
+impl SignalUnit {
+    /// Unit after integration over time
+    ///
+    /// Returns the resulting unit and the factor the integrated values
+    /// have to be multiplied by, or `None` if the unit has no integral
+    /// in the acceleration → velocity → displacement chain.
+    /// Accelerations in g become velocities in mm/s and velocities in mm/s
+    /// become displacements in µm, as used by vibration severity standards.
+    pub fn integrated(&self) -> Option<(SignalUnit, f64)> {
+        match self {
+            SignalUnit::Gravity => Some((SignalUnit::MillimetersPerSecond, STANDARD_GRAVITY * 1e3)),
+            SignalUnit::MetersPerSecondSquared => Some((SignalUnit::MetersPerSecond, 1.0)),
+            SignalUnit::MetersPerSecond => Some((SignalUnit::Meters, 1.0)),
+            SignalUnit::MillimetersPerSecond => Some((SignalUnit::Micrometers, 1e3)),
+            _ => None,
+        }
+    }
+
+    /// Unit after differentiation over time
+    ///
+    /// The inverse of [`SignalUnit::integrated`], displacements in mm
+    /// become velocities in mm/s.
+    pub fn differentiated(&self) -> Option<(SignalUnit, f64)> {
+        match self {
+            SignalUnit::MillimetersPerSecond => {
+                Some((SignalUnit::Gravity, 1e-3 / STANDARD_GRAVITY))
+            }
+            SignalUnit::MetersPerSecond => Some((SignalUnit::MetersPerSecondSquared, 1.0)),
+            SignalUnit::Meters => Some((SignalUnit::MetersPerSecond, 1.0)),
+            SignalUnit::Millimeters => Some((SignalUnit::MillimetersPerSecond, 1.0)),
+            SignalUnit::Micrometers => Some((SignalUnit::MillimetersPerSecond, 1e-3)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+#[derive(Debug, Clone, Default)]
+pub struct SpectralPeak {
+    pub frequency: f64,
+    pub amplitude: f64,
+    pub prominence: f64,
+    pub harmonic: Option<usize>,
+}
+
+impl SpectralPeak {
+    pub fn new(frequency: f64, amplitude: f64, prominence: f64) -> Self {
+        Self {
+            frequency,
+            amplitude,
+            prominence,
+            harmonic: None,
+        }
+    }
+}
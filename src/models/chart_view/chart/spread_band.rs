@@ -0,0 +1,13 @@
+use crate::models::chart_view::chart::point::Point;
+
+#[derive(Debug, Clone, Default)]
+pub struct SpreadBand {
+    pub lower: Vec<Point>,
+    pub upper: Vec<Point>,
+}
+
+impl SpreadBand {
+    pub fn new(lower: Vec<Point>, upper: Vec<Point>) -> Self {
+        Self { lower, upper }
+    }
+}
@@ -0,0 +1,11 @@
+use rustfft::num_complex::Complex;
+
+#[derive(Debug, Clone, Default)]
+pub struct StftFrames {
+    pub frames: Vec<Vec<Complex<f64>>>,
+    pub window: Vec<f64>,
+    pub hop_size: usize,
+    pub signal_len: usize,
+    pub sample_rate: f32,
+    pub start: f64,
+}
@@ -0,0 +1,214 @@
+use std::{fmt, str::FromStr, sync::Arc};
+
+use strum_macros::{Display, EnumString};
+
+use crate::{
+    models::chart_view::chart::point::Point,
+    shared::constants::wavelet::{
+        COIF1, COIF2, COIF3, DB2, DB3, DB4, DB5, DB6, DB7, DB8, DB9, DB10, HAAR, SYM2, SYM3, SYM4,
+        SYM5, SYM6, SYM7, SYM8, SYM9, SYM10,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wavelet {
+    Haar,
+    Daubechies(u8),
+    Symlet(u8),
+    Coiflet(u8),
+}
+
+impl Wavelet {
+    /// Decomposition low-pass filter
+    ///
+    /// The remaining filters of the orthogonal filter bank are derived from it.
+    pub fn decomposition_low(&self) -> &'static [f64] {
+        match self {
+            Wavelet::Haar => &HAAR,
+            Wavelet::Daubechies(2) => &DB2,
+            Wavelet::Daubechies(3) => &DB3,
+            Wavelet::Daubechies(4) => &DB4,
+            Wavelet::Daubechies(5) => &DB5,
+            Wavelet::Daubechies(6) => &DB6,
+            Wavelet::Daubechies(7) => &DB7,
+            Wavelet::Daubechies(8) => &DB8,
+            Wavelet::Daubechies(9) => &DB9,
+            Wavelet::Daubechies(_) => &DB10,
+            Wavelet::Symlet(2) => &SYM2,
+            Wavelet::Symlet(3) => &SYM3,
+            Wavelet::Symlet(4) => &SYM4,
+            Wavelet::Symlet(5) => &SYM5,
+            Wavelet::Symlet(6) => &SYM6,
+            Wavelet::Symlet(7) => &SYM7,
+            Wavelet::Symlet(8) => &SYM8,
+            Wavelet::Symlet(9) => &SYM9,
+            Wavelet::Symlet(_) => &SYM10,
+            Wavelet::Coiflet(1) => &COIF1,
+            Wavelet::Coiflet(2) => &COIF2,
+            Wavelet::Coiflet(_) => &COIF3,
+        }
+    }
+
+    /// Decomposition high-pass filter (quadrature mirror of the low-pass one)
+    pub fn decomposition_high(&self) -> Vec<f64> {
+        let low = self.decomposition_low();
+        (0..low.len())
+            .map(|k| {
+                let sign = if k % 2 == 0 { -1.0 } else { 1.0 };
+                sign * low[low.len() - 1 - k]
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Wavelet {
+    type Err = strum::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let order = |prefix: &str, range: std::ops::RangeInclusive<u8>| {
+            s.strip_prefix(prefix)
+                .and_then(|order| order.parse::<u8>().ok())
+                .filter(|order| range.contains(order))
+        };
+        if s == "haar" {
+            Ok(Wavelet::Haar)
+        } else if let Some(order) = order("db", 2..=10) {
+            Ok(Wavelet::Daubechies(order))
+        } else if let Some(order) = order("sym", 2..=10) {
+            Ok(Wavelet::Symlet(order))
+        } else if let Some(order) = order("coif", 1..=3) {
+            Ok(Wavelet::Coiflet(order))
+        } else {
+            Err(strum::ParseError::VariantNotFound)
+        }
+    }
+}
+
+impl fmt::Display for Wavelet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Wavelet::Haar => write!(f, "haar"),
+            Wavelet::Daubechies(order) => write!(f, "db{}", order),
+            Wavelet::Symlet(order) => write!(f, "sym{}", order),
+            Wavelet::Coiflet(order) => write!(f, "coif{}", order),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumString, Display)]
+pub enum ExtensionMode {
+    #[default]
+    #[strum(serialize = "sym")]
+    Symmetric,
+    #[strum(serialize = "reflect")]
+    Reflect,
+    #[strum(serialize = "per")]
+    Periodic,
+    #[strum(serialize = "const")]
+    Constant,
+    #[strum(serialize = "zero")]
+    Zero,
+}
+
+impl ExtensionMode {
+    /// Sample of the signal extended beyond its boundaries
+    ///
+    /// ---
+    ///
+    /// * `signal`: The signal to extend, should not be empty.
+    /// * `index`: The position of the sample, may lie outside of the signal.
+    pub fn sample(&self, signal: &[f64], index: isize) -> f64 {
+        let len = signal.len() as isize;
+        if (0..len).contains(&index) {
+            return signal[index as usize];
+        }
+        match self {
+            ExtensionMode::Zero => 0.0,
+            ExtensionMode::Constant => signal[index.clamp(0, len - 1) as usize],
+            ExtensionMode::Periodic => signal[index.rem_euclid(len) as usize],
+            ExtensionMode::Symmetric => {
+                let period = 2 * len;
+                let folded = index.rem_euclid(period);
+                signal[(if folded < len {
+                    folded
+                } else {
+                    period - 1 - folded
+                }) as usize]
+            }
+            ExtensionMode::Reflect => {
+                if len == 1 {
+                    return signal[0];
+                }
+                let period = 2 * (len - 1);
+                let folded = index.rem_euclid(period);
+                signal[(if folded < len {
+                    folded
+                } else {
+                    period - folded
+                }) as usize]
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WaveletDecomposition {
+    pub wavelet: Wavelet,
+    pub mode: ExtensionMode,
+    pub approximation: Vec<f64>,
+    pub details: Vec<Vec<f64>>,
+    pub lengths: Vec<usize>,
+    pub sample_rate: f32,
+    pub start: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaveletBand {
+    Detail(usize),
+    Approximation,
+}
+
+#[derive(Debug, Clone)]
+pub struct WaveletCoefficients {
+    pub decomposition: Arc<WaveletDecomposition>,
+    pub band: WaveletBand,
+}
+
+impl WaveletDecomposition {
+    pub fn levels(&self) -> usize {
+        self.details.len()
+    }
+
+    /// Detail coefficients of a level as chart points
+    ///
+    /// Coefficient `i` of level `j` is placed at the time `i * 2^j / sample_rate`
+    /// from the beginning of the decomposed signal.
+    ///
+    /// ---
+    ///
+    /// * `level`: The decomposition level, starting from 1.
+    pub fn detail_points(&self, level: usize) -> Vec<Point> {
+        self.coefficient_points(&self.details[level - 1], level)
+    }
+
+    /// Final approximation coefficients as chart points
+    pub fn approximation_points(&self) -> Vec<Point> {
+        self.coefficient_points(&self.approximation, self.levels())
+    }
+
+    /// Sample rate of the coefficients at the given level
+    ///
+    /// Levels are clamped to the depth of the decomposition.
+    pub fn level_sample_rate(&self, level: usize) -> f32 {
+        self.sample_rate / 2f32.powi(level.min(self.levels()) as i32)
+    }
+
+    fn coefficient_points(&self, coefficients: &[f64], level: usize) -> Vec<Point> {
+        let dt = 1.0 / self.level_sample_rate(level) as f64;
+        coefficients
+            .iter()
+            .enumerate()
+            .map(|(i, v)| Point::new(self.start + i as f64 * dt, *v))
+            .collect()
+    }
+}
@@ -0,0 +1,61 @@
+use ratatui::style::Color;
+use strum_macros::{Display, EnumString};
+
+const VIRIDIS: [(u8, u8, u8); 5] = [
+    (68, 1, 84),
+    (59, 82, 139),
+    (33, 145, 140),
+    (94, 201, 98),
+    (253, 231, 37),
+];
+const MAGMA: [(u8, u8, u8); 5] = [
+    (0, 0, 4),
+    (81, 18, 124),
+    (183, 55, 121),
+    (252, 137, 97),
+    (252, 253, 191),
+];
+const JET: [(u8, u8, u8); 5] = [
+    (0, 0, 143),
+    (0, 128, 255),
+    (127, 255, 127),
+    (255, 128, 0),
+    (128, 0, 0),
+];
+const GRAY: [(u8, u8, u8); 2] = [(0, 0, 0), (255, 255, 255)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumString, Display)]
+pub enum Colormap {
+    #[default]
+    #[strum(serialize = "viridis")]
+    Viridis,
+    #[strum(serialize = "magma")]
+    Magma,
+    #[strum(serialize = "jet")]
+    Jet,
+    #[strum(serialize = "gray")]
+    Gray,
+}
+
+impl Colormap {
+    /// Map a normalized value to a color
+    ///
+    /// ---
+    ///
+    /// * `t`: The value to map, clamped to the range [0, 1].
+    pub fn color(&self, t: f64) -> Color {
+        let stops: &[(u8, u8, u8)] = match self {
+            Colormap::Viridis => &VIRIDIS,
+            Colormap::Magma => &MAGMA,
+            Colormap::Jet => &JET,
+            Colormap::Gray => &GRAY,
+        };
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let position = t * (stops.len() - 1) as f64;
+        let index = (position.floor() as usize).min(stops.len() - 2);
+        let frac = position - index as f64;
+        let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * frac).round() as u8;
+        let (from, to) = (stops[index], stops[index + 1]);
+        Color::Rgb(lerp(from.0, to.0), lerp(from.1, to.1), lerp(from.2, to.2))
+    }
+}
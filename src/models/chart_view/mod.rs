@@ -1,2 +1,5 @@
 pub mod canvas_style;
 pub mod chart;
+pub mod colormap;
+pub mod pipeline;
+pub mod statistics_scope;
@@ -0,0 +1,131 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{Error, Result, anyhow};
+use strum_macros::{Display, EnumString};
+
+use crate::shared::{
+    commands::chart_view::ProcessingCommands,
+    constants::pipeline::{PIPELINE_NAME_SEPARATOR, PIPELINE_SKIP_PREFIX, PIPELINE_STEP_SEPARATOR},
+    errors::pipeline::PipelineError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum PipelineAction {
+    #[strum(serialize = "define")]
+    Define,
+    #[strum(serialize = "run")]
+    Run,
+    #[strum(serialize = "batch")]
+    Batch,
+    #[strum(serialize = "save")]
+    Save,
+    #[strum(serialize = "load")]
+    Load,
+}
+
+/// Whether `:pipe save` may replace an existing file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum SaveMode {
+    #[strum(serialize = "new")]
+    New,
+    #[strum(serialize = "force")]
+    Overwrite,
+}
+
+/// Single command of a pipeline.
+///
+/// The charts of a step are added to the chart list unless the step
+/// is marked with the skip prefix, e.g. `-:detrend linear`.
+#[derive(Debug, Clone)]
+pub struct PipelineStep {
+    pub command: ProcessingCommands,
+    pub cmd: String,
+    pub keep: bool,
+}
+
+impl FromStr for PipelineStep {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let step = s.trim();
+        let (cmd, keep) = match step.strip_prefix(PIPELINE_SKIP_PREFIX) {
+            Some(cmd) => (cmd.trim(), false),
+            None => (step, true),
+        };
+        let command = cmd
+            .split_whitespace()
+            .next()
+            .and_then(|name| ProcessingCommands::from_str(name).ok())
+            .filter(ProcessingCommands::creates_charts)
+            .ok_or_else(|| anyhow!(PipelineError::InvalidStep(String::from(step))))?;
+        Ok(Self {
+            command,
+            cmd: cmd.split_whitespace().collect::<Vec<&str>>().join(" "),
+            keep,
+        })
+    }
+}
+
+impl fmt::Display for PipelineStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.keep {
+            write!(f, "{}", PIPELINE_SKIP_PREFIX)?;
+        }
+        write!(f, "{}", self.cmd)
+    }
+}
+
+/// Named sequence of processing commands.
+///
+/// Every step runs on the first chart created by the previous one.
+/// Pipelines are stored one per line as `name = step ; step ; ...`.
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    pub name: String,
+    pub steps: Vec<PipelineStep>,
+}
+
+impl Pipeline {
+    /// Create a pipeline from its name and `;` separated steps.
+    pub fn new(name: &str, steps: &str) -> Result<Self> {
+        let name = name.trim();
+        if name.is_empty() || name.contains(PIPELINE_NAME_SEPARATOR) {
+            return Err(anyhow!(PipelineError::InvalidName));
+        }
+        let steps = steps
+            .split(PIPELINE_STEP_SEPARATOR)
+            .filter(|step| !step.trim().is_empty())
+            .map(PipelineStep::from_str)
+            .collect::<Result<Vec<_>>>()?;
+        if steps.is_empty() {
+            return Err(anyhow!(PipelineError::EmptyPipeline));
+        }
+        Ok(Self {
+            name: String::from(name),
+            steps,
+        })
+    }
+}
+
+impl FromStr for Pipeline {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, steps) = s
+            .split_once(PIPELINE_NAME_SEPARATOR)
+            .ok_or_else(|| anyhow!(PipelineError::Syntax(String::from(s.trim()))))?;
+        Self::new(name, steps)
+    }
+}
+
+impl fmt::Display for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let steps = self
+            .steps
+            .iter()
+            .map(PipelineStep::to_string)
+            .collect::<Vec<String>>()
+            .join(&format!(" {} ", PIPELINE_STEP_SEPARATOR));
+        write!(f, "{} {} {}", self.name, PIPELINE_NAME_SEPARATOR, steps)
+    }
+}
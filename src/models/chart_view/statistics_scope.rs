@@ -0,0 +1,12 @@
+use strum_macros::{Display, EnumString};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumString, Display)]
+pub enum StatisticsScope {
+    #[default]
+    #[strum(serialize = "off")]
+    Off,
+    #[strum(serialize = "all")]
+    Chart,
+    #[strum(serialize = "view")]
+    View,
+}
@@ -4,4 +4,6 @@ use strum_macros::{AsRefStr, EnumString};
 pub enum FileType {
     #[strum(serialize = "bin")]
     Vibric,
+    #[strum(serialize = "csv")]
+    Csv,
 }
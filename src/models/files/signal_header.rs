@@ -1,3 +1,4 @@
+#[derive(Debug, Clone)]
 pub struct SignalHeader {
     pub signature: [u8; 4],
     pub channels: u32,
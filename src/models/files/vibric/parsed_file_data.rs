@@ -1,17 +1,24 @@
-use crate::models::chart_view::chart::point::Point;
+use crate::models::{chart_view::chart::point::Point, files::signal_header::SignalHeader};
 
 pub struct ParsedFileData {
     pub data: Vec<Point>,
     pub sample_rate: f32,
     pub chart_title: String,
+    pub header: SignalHeader,
 }
 
 impl ParsedFileData {
-    pub fn new(data: Vec<Point>, sample_rate: f32, chart_title: String) -> Self {
+    pub fn new(
+        data: Vec<Point>,
+        sample_rate: f32,
+        chart_title: String,
+        header: SignalHeader,
+    ) -> Self {
         Self {
             data,
             sample_rate,
             chart_title,
+            header,
         }
     }
 }
@@ -1,14 +1,49 @@
+use std::{
+    f64::consts::{FRAC_1_SQRT_2, PI, SQRT_2, TAU},
+    sync::Arc,
+};
+
 use anyhow::{Result, anyhow};
-use kofft::{Complex32, stft::stft, wavelet::haar_forward_inplace_stack, window::hann};
-use rustfft::{FftPlanner, num_complex::Complex};
+use rustfft::{FftDirection, FftPlanner, num_complex::Complex};
+
+use strum_macros::{Display, EnumString};
 
 use crate::{
     models::chart_view::chart::{
-        chart_model::ChartModel, chart_transform::ChartTransform, point::Point,
+        cepstrum::Cepstrum,
+        chart_model::ChartModel,
+        complex_spectrum::ComplexSpectrum,
+        heatmap::Heatmap,
+        octave_spectrum::{OctaveBand, OctaveSpectrum},
+        point::Point,
+        signal_statistics::SignalStatistics,
+        spectral_peak::SpectralPeak,
+        spread_band::SpreadBand,
+        stft_frames::StftFrames,
+        wavelet::{ExtensionMode, Wavelet, WaveletBand, WaveletDecomposition},
+    },
+    services::{detrend::DetrendType, least_squares, window::WindowType, worker::JobProgress},
+    shared::{
+        constants::chart_processing::{
+            DEFAULT_WELCH_OVERLAP, HARMONIC_TOLERANCE, MAX_SPECTRAL_PEAKS,
+        },
+        errors::chart_processing::ChartProcessingError,
     },
-    shared::errors::chart_processing::ChartProcessingError,
+    utils::expression::Expression,
 };
 
+const MIN_AMPLITUDE: f64 = 1e-12;
+const MORLET_OMEGA: f64 = 6.0;
+const PAUL_ORDER: i32 = 4;
+const GAMMA_2_5: f64 = 1.329_340_388_179_137;
+const CWT_MAX_COLUMNS: usize = 4096;
+const DECIMATION_TAPS_PER_FACTOR: usize = 20;
+const MAD_SCALE: f64 = 1.482_6;
+const EXPONENTIAL_AVERAGES: usize = 8;
+const OCTAVE_RATIO: f64 = 1.995_262_314_968_879_5;
+const OCTAVE_REFERENCE: f64 = 1000.0;
+const NOMINAL_THIRD_OCTAVES: [f64; 10] = [1.0, 1.25, 1.6, 2.0, 2.5, 3.15, 4.0, 5.0, 6.3, 8.0];
+
 #[derive(Debug, Clone, Copy)]
 pub enum FftFilterType {
     LowPass(f64),
@@ -17,8 +52,228 @@ pub enum FftFilterType {
     BandStop(f64, f64),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+pub enum PhaseMode {
+    #[strum(serialize = "wrap")]
+    Wrapped,
+    #[strum(serialize = "unwrap")]
+    Unwrapped,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+pub enum AngleUnit {
+    #[strum(serialize = "rad")]
+    Radians,
+    #[strum(serialize = "deg")]
+    Degrees,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+pub enum SpectralAveraging {
+    #[strum(serialize = "mean")]
+    Mean,
+    #[strum(serialize = "median")]
+    Median,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum BlockAveraging {
+    #[strum(serialize = "linear")]
+    Linear,
+    #[strum(serialize = "exp")]
+    Exponential,
+    #[strum(serialize = "peak")]
+    PeakHold,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+pub enum BlockSpread {
+    #[strum(serialize = "none")]
+    None,
+    #[strum(serialize = "minmax")]
+    MinMax,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum OctaveFraction {
+    #[strum(to_string = "1/1", serialize = "1")]
+    Octave,
+    #[strum(to_string = "1/3", serialize = "3")]
+    ThirdOctave,
+}
+
+impl OctaveFraction {
+    fn bands_per_octave(&self) -> i32 {
+        match self {
+            OctaveFraction::Octave => 1,
+            OctaveFraction::ThirdOctave => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum FrfEstimator {
+    #[strum(serialize = "h1")]
+    H1,
+    #[strum(serialize = "h2")]
+    H2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+pub enum PeakInterpolation {
+    #[strum(serialize = "parabolic")]
+    Parabolic,
+    #[strum(serialize = "gaussian")]
+    Gaussian,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+pub enum CepstrumType {
+    #[strum(serialize = "real")]
+    Real,
+    #[strum(serialize = "power")]
+    Power,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum LifterType {
+    #[strum(serialize = "low")]
+    ShortPass,
+    #[strum(serialize = "high")]
+    LongPass,
+    #[strum(serialize = "comb")]
+    Comb,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum CalculusMethod {
+    #[strum(serialize = "freq")]
+    Frequency,
+    #[strum(serialize = "time")]
+    Time,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum ArithmeticOperation {
+    #[strum(serialize = "+")]
+    Add,
+    #[strum(serialize = "-")]
+    Subtract,
+    #[strum(serialize = "*")]
+    Multiply,
+    #[strum(serialize = "/")]
+    Divide,
+}
+
+impl ArithmeticOperation {
+    pub fn apply(&self, left: f64, right: f64) -> f64 {
+        match self {
+            ArithmeticOperation::Add => left + right,
+            ArithmeticOperation::Subtract => left - right,
+            ArithmeticOperation::Multiply => left * right,
+            ArithmeticOperation::Divide => left / right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum SmoothingType {
+    #[strum(serialize = "ma")]
+    MovingAverage,
+    #[strum(serialize = "ema")]
+    ExponentialMovingAverage,
+    #[strum(serialize = "median")]
+    Median,
+    #[strum(serialize = "sg")]
+    SavitzkyGolay,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum OutlierDetector {
+    #[strum(serialize = "hampel")]
+    Hampel,
+    #[strum(serialize = "zscore")]
+    ZScore,
+    #[strum(serialize = "mad")]
+    Mad,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumString, Display)]
+pub enum RepairMode {
+    #[default]
+    #[strum(serialize = "interp")]
+    Interpolate,
+    #[strum(serialize = "hold")]
+    Hold,
+    #[strum(serialize = "remove")]
+    Remove,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+pub enum CorrelationScale {
+    #[strum(serialize = "none")]
+    None,
+    #[strum(serialize = "biased")]
+    Biased,
+    #[strum(serialize = "unbiased")]
+    Unbiased,
+    #[strum(serialize = "coeff")]
+    Coefficient,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+pub enum ContinuousWavelet {
+    #[strum(serialize = "morlet")]
+    Morlet,
+    #[strum(serialize = "mexh")]
+    MexicanHat,
+    #[strum(serialize = "paul")]
+    Paul,
+}
+
+impl ContinuousWavelet {
+    /// Ratio between the equivalent Fourier period and the wavelet scale.
+    fn fourier_factor(&self) -> f64 {
+        match self {
+            ContinuousWavelet::Morlet => {
+                2.0 * TAU / (MORLET_OMEGA + (2.0 + MORLET_OMEGA.powi(2)).sqrt())
+            }
+            ContinuousWavelet::MexicanHat => TAU / 2.5_f64.sqrt(),
+            ContinuousWavelet::Paul => 2.0 * TAU / (2.0 * PAUL_ORDER as f64 + 1.0),
+        }
+    }
+
+    /// Ratio between the e-folding time of the wavelet power and the scale.
+    fn e_folding(&self) -> f64 {
+        match self {
+            ContinuousWavelet::Morlet | ContinuousWavelet::MexicanHat => SQRT_2,
+            ContinuousWavelet::Paul => FRAC_1_SQRT_2,
+        }
+    }
+
+    /// Fourier transform of the unit-energy wavelet at the dimensionless frequency `s * omega`.
+    fn spectrum(&self, scaled_omega: f64) -> f64 {
+        match self {
+            ContinuousWavelet::Morlet if scaled_omega > 0.0 => {
+                PI.powf(-0.25) * (-(scaled_omega - MORLET_OMEGA).powi(2) / 2.0).exp()
+            }
+            ContinuousWavelet::Paul if scaled_omega > 0.0 => {
+                let factorial: f64 = (1..=2 * PAUL_ORDER).map(|i| i as f64).product();
+                2_f64.powi(PAUL_ORDER) / (PAUL_ORDER as f64 * factorial).sqrt()
+                    * scaled_omega.powi(PAUL_ORDER)
+                    * (-scaled_omega).exp()
+            }
+            ContinuousWavelet::MexicanHat => {
+                scaled_omega.powi(2) * (-scaled_omega.powi(2) / 2.0).exp() / GAMMA_2_5.sqrt()
+            }
+            _ => 0.0,
+        }
+    }
+}
+
 pub struct ChartProcessingService {
     planner: FftPlanner<f64>,
+    progress: Arc<JobProgress>,
 }
 
 impl Default for ChartProcessingService {
@@ -31,53 +286,229 @@ impl ChartProcessingService {
     pub fn new() -> Self {
         Self {
             planner: FftPlanner::new(),
+            progress: Arc::new(JobProgress::default()),
         }
     }
 
+    /// Set the progress of the job the service is running for.
+    ///
+    /// Long loops report to it and stop once the job is cancelled.
+    pub fn set_progress(&mut self, progress: Arc<JobProgress>) {
+        self.progress = progress;
+    }
+
+    /// Run an FFT in place
+    ///
+    /// A single transform cannot be interrupted, so cancellation is checked
+    /// before it and the transform counts as one step of the job once done.
+    ///
+    /// ---
+    ///
+    /// * `buffer`: The samples or bins to transform.
+    /// * `direction`: Whether the forward or the inverse transform is run.
+    fn transform(&mut self, buffer: &mut [Complex<f64>], direction: FftDirection) -> Result<()> {
+        self.progress.check()?;
+        self.planner
+            .plan_fft(buffer.len(), direction)
+            .process(buffer);
+        self.progress.advance()
+    }
+
     /// Apply FFT transformation
     ///
-    /// Execute FFT transformation on the given STANDARD view chart data.
+    /// Execute FFT transformation on the given STANDARD view chart data
+    /// and return its magnitude spectrum.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be transformed.
+    /// * `detrend`: The optional trend to remove before the transformation.
+    pub fn fft_forward(
+        &mut self,
+        chart: &ChartModel,
+        detrend: Option<DetrendType>,
+    ) -> Result<Vec<Point>> {
+        let spectrum = self.fft_complex(chart, detrend)?;
+        Ok(self.spectrum_magnitude(&spectrum))
+    }
+
+    /// Compute complex FFT spectrum
+    ///
+    /// Execute FFT transformation on the given STANDARD view chart data
+    /// and keep the complete complex output, so phase information survives.
     ///
     /// ---
     ///
     /// * `chart`: The chart data to be transformed.
-    pub fn fft_forward(&mut self, chart: &ChartModel) -> Result<Vec<Point>> {
-        if chart.metadata.transform != ChartTransform::Standard {
+    /// * `detrend`: The optional trend to remove before the transformation.
+    pub fn fft_complex(
+        &mut self,
+        chart: &ChartModel,
+        detrend: Option<DetrendType>,
+    ) -> Result<ComplexSpectrum> {
+        if !chart.metadata.transform.is_time_domain() {
             return Err(anyhow!(ChartProcessingError::NonStandard));
         }
-        let mut buffer: Vec<Complex<f64>> =
-            chart.data.iter().map(|p| Complex::new(p.y, 0.0)).collect();
+        let samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        let samples = match detrend {
+            Some(detrend) => detrend.apply(&samples),
+            None => samples,
+        };
+        let mut buffer: Vec<Complex<f64>> = samples.iter().map(|y| Complex::new(*y, 0.0)).collect();
 
-        let fft = self.planner.plan_fft_forward(buffer.len());
-        fft.process(&mut buffer);
-
-        let n = buffer.len();
-        let freq_res = chart.sample_rate / n as f32;
+        self.progress.begin(1);
+        self.transform(&mut buffer, FftDirection::Forward)?;
 
-        let mut shifted = vec![Complex::new(0.0, 0.0); n];
-        let half = n / 2;
-        shifted[..half].copy_from_slice(&buffer[half..(half + half)]);
-        shifted[half..(half + half)].copy_from_slice(&buffer[..half]);
+        Ok(ComplexSpectrum::new(
+            buffer,
+            chart.sample_rate,
+            chart.data.first().map(|p| p.x).unwrap_or_default(),
+        ))
+    }
 
-        Ok(shifted
+    /// Magnitude spectrum
+    ///
+    /// Convert a complex spectrum into single-sided amplitude points,
+    /// dropping the bins with negligible amplitude.
+    ///
+    /// ---
+    ///
+    /// * `spectrum`: The complex spectrum to convert.
+    pub fn spectrum_magnitude(&self, spectrum: &ComplexSpectrum) -> Vec<Point> {
+        spectrum
+            .one_sided()
             .iter()
-            .enumerate()
-            .filter_map(|(i, c)| {
-                let freq = (i as f64 - half as f64) * freq_res as f64;
-                let norm = c.norm() / n as f64 * 2.0;
-                if freq < 0.0 || norm < 0.1 {
+            .filter_map(|(freq, c)| {
+                let norm = c.norm();
+                if norm < 0.1 {
                     None
                 } else {
-                    Some(Point::new(freq, norm))
+                    Some(Point::new(*freq, norm))
                 }
             })
+            .collect()
+    }
+
+    /// Phase spectrum
+    ///
+    /// Extract the phase of the complex spectrum attached to an FFT chart.
+    /// Bins whose amplitude is below `threshold` carry no meaningful phase
+    /// and are masked out.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The FFT chart holding the complex spectrum.
+    /// * `mode`: Whether the phase is wrapped to (-pi, pi] or unwrapped.
+    /// * `unit`: The angle unit of the result.
+    /// * `threshold`: The minimal amplitude of the bins to keep.
+    pub fn phase_spectrum(
+        &self,
+        chart: &ChartModel,
+        mode: PhaseMode,
+        unit: AngleUnit,
+        threshold: f64,
+    ) -> Result<Vec<Point>> {
+        let spectrum = Self::chart_spectrum(chart)?;
+        let (freqs, mut phases): (Vec<f64>, Vec<f64>) = spectrum
+            .one_sided()
+            .iter()
+            .filter(|(_, c)| c.norm() >= threshold)
+            .map(|(freq, c)| (*freq, c.arg()))
+            .unzip();
+        if mode == PhaseMode::Unwrapped {
+            Self::unwrap_phase(&mut phases);
+        }
+        Ok(freqs
+            .iter()
+            .zip(phases)
+            .map(|(freq, phase)| {
+                let phase = match unit {
+                    AngleUnit::Radians => phase,
+                    AngleUnit::Degrees => phase.to_degrees(),
+                };
+                Point::new(*freq, phase)
+            })
+            .collect())
+    }
+
+    /// Real part of the spectrum
+    ///
+    /// Extract the real part of the complex spectrum attached to an FFT chart.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The FFT chart holding the complex spectrum.
+    pub fn real_spectrum(&self, chart: &ChartModel) -> Result<Vec<Point>> {
+        Ok(Self::chart_spectrum(chart)?
+            .one_sided()
+            .iter()
+            .map(|(freq, c)| Point::new(*freq, c.re))
+            .collect())
+    }
+
+    /// Imaginary part of the spectrum
+    ///
+    /// Extract the imaginary part of the complex spectrum attached to an FFT chart.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The FFT chart holding the complex spectrum.
+    pub fn imaginary_spectrum(&self, chart: &ChartModel) -> Result<Vec<Point>> {
+        Ok(Self::chart_spectrum(chart)?
+            .one_sided()
+            .iter()
+            .map(|(freq, c)| Point::new(*freq, c.im))
             .collect())
     }
 
+    /// Bode plot
+    ///
+    /// Build the Bode-style pair from the complex spectrum attached to an FFT chart:
+    /// magnitude in dB and unwrapped phase in degrees.
+    /// Both are masked where the amplitude is below `threshold`.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The FFT chart holding the complex spectrum.
+    /// * `threshold`: The minimal amplitude of the bins to keep.
+    pub fn bode(&self, chart: &ChartModel, threshold: f64) -> Result<(Vec<Point>, Vec<Point>)> {
+        let magnitude = Self::chart_spectrum(chart)?
+            .one_sided()
+            .iter()
+            .filter(|(_, c)| c.norm() >= threshold)
+            .map(|(freq, c)| Point::new(*freq, 20.0 * c.norm().log10()))
+            .collect();
+        let phase =
+            self.phase_spectrum(chart, PhaseMode::Unwrapped, AngleUnit::Degrees, threshold)?;
+        Ok((magnitude, phase))
+    }
+
+    fn chart_spectrum(chart: &ChartModel) -> Result<&ComplexSpectrum> {
+        chart
+            .spectrum
+            .as_ref()
+            .ok_or(anyhow!(ChartProcessingError::NoSpectrum))
+    }
+
+    fn unwrap_phase(phases: &mut [f64]) {
+        let mut offset = 0.0;
+        let mut previous: Option<f64> = None;
+        for phase in phases.iter_mut() {
+            let raw = *phase;
+            if let Some(previous) = previous {
+                offset -= TAU * ((raw - previous) / TAU).round();
+            }
+            previous = Some(raw);
+            *phase = raw + offset;
+        }
+    }
+
     /// Apply STFT transformation
     ///
-    /// Execute STFT transformation on the given STANDARD view chart data.
-    /// Use the stake-only approach to reduce memory usage.
+    /// Execute STFT transformation on the given STANDARD view chart data
+    /// and keep every complex frame.
+    /// Frames are centered on multiples of `hop_size`, the signal is zero padded
+    /// by half a window on both sides.
     ///
     /// ---
     ///
@@ -85,35 +516,91 @@ impl ChartProcessingService {
     /// * `window_size`: The size of the Hann window to use for the STFT.
     /// * `hop_size`: The hop size between frames.
     pub fn stft_forward(
-        &self,
+        &mut self,
         chart: &ChartModel,
         window_size: usize,
         hop_size: usize,
-    ) -> Result<Vec<Point>> {
-        if chart.metadata.transform != ChartTransform::Standard {
+    ) -> Result<StftFrames> {
+        if !chart.metadata.transform.is_time_domain() {
             return Err(anyhow!(ChartProcessingError::NonStandard));
         }
-        let y: Vec<f32> = chart.data.iter().map(|point| point.y as f32).collect();
-        let window = hann(window_size);
-        let fft = vec![Complex32::new(0.0, 0.0); window.len()];
-        let mut frames = vec![fft; y.len().div_ceil(hop_size)];
-        if stft(&y, &window, hop_size, &mut frames[..]).is_err() {
+        if window_size < 2 || hop_size == 0 || window_size > chart.data.len() {
             return Err(anyhow!(ChartProcessingError::StftError));
         }
+        let samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        let window = WindowType::Hann.coefficients(window_size);
+        Ok(StftFrames {
+            frames: self.stft_frames(&samples, &window, hop_size)?,
+            window,
+            hop_size,
+            signal_len: samples.len(),
+            sample_rate: chart.sample_rate,
+            start: chart.data.first().map(|p| p.x).unwrap_or_default(),
+        })
+    }
 
-        let res = frames
+    /// Spectrogram of STFT frames
+    ///
+    /// Build the time-frequency matrix of single-sided amplitudes in dB,
+    /// with frame times in seconds and bin frequencies in Hz.
+    ///
+    /// ---
+    ///
+    /// * `stft`: The STFT frames to convert.
+    pub fn spectrogram(&self, stft: &StftFrames) -> Heatmap {
+        let fs = stft.sample_rate as f64;
+        let window_size = stft.window.len();
+        let amplitude_scale = 2.0 / stft.window.iter().sum::<f64>();
+        let bins = window_size / 2 + 1;
+        let times = (0..stft.frames.len())
+            .map(|m| stft.start + (m * stft.hop_size) as f64 / fs)
+            .collect();
+        let freqs = (0..bins)
+            .map(|k| k as f64 * fs / window_size as f64)
+            .collect();
+        let values = stft
+            .frames
             .iter()
-            .enumerate()
-            .map(|(t, frame)| {
-                let avg = frame
+            .map(|frame| {
+                frame[..bins]
                     .iter()
-                    .map(|c| c.re.powi(2) + c.im.powi(2))
-                    .sum::<f32>() as f64
-                    / frame.len() as f64;
-                Point::new(t as f64, avg)
+                    .map(|c| 20.0 * (c.norm() * amplitude_scale).max(MIN_AMPLITUDE).log10())
+                    .collect()
             })
             .collect();
-        Ok(res)
+        Heatmap::new(times, freqs, values, "dB")
+    }
+
+    /// Split samples into centered windowed frames and transform them.
+    fn stft_frames(
+        &mut self,
+        samples: &[f64],
+        window: &[f64],
+        hop_size: usize,
+    ) -> Result<Vec<Vec<Complex<f64>>>> {
+        let window_size = window.len();
+        let pad = window_size / 2;
+        let fft = self.planner.plan_fft_forward(window_size);
+        let frames = (samples.len() + pad - 1) / hop_size + 1;
+        (0..frames)
+            .map(|m| {
+                self.progress.report(m, frames)?;
+                let mut frame: Vec<Complex<f64>> = window
+                    .iter()
+                    .enumerate()
+                    .map(|(i, w)| {
+                        let y = (m * hop_size + i)
+                            .checked_sub(pad)
+                            .and_then(|index| samples.get(index))
+                            .copied()
+                            .unwrap_or_default();
+                        Complex::new(y * w, 0.0)
+                    })
+                    .collect();
+                fft.process(&mut frame);
+                Ok(frame)
+            })
+            .collect()
     }
 
     /// Apply FFT filter
@@ -147,38 +634,2149 @@ impl ChartProcessingService {
             .collect())
     }
 
-    /// Apply Wavelet Transform.
+    /// Apply FFT filter to a complex spectrum
+    ///
+    /// Zero the bins outside of the filter pass band, mirroring the decision
+    /// for negative frequencies, so the filtered spectrum stays invertible.
+    ///
+    /// ---
+    ///
+    /// * `spectrum`: The complex spectrum to filter.
+    /// * `filter`: The type of filter to apply (LowPass, HighPass, BandPass, BandStop).
+    pub fn filter_spectrum(
+        &self,
+        spectrum: &ComplexSpectrum,
+        filter: FftFilterType,
+    ) -> ComplexSpectrum {
+        let n = spectrum.bins.len();
+        let freq_res = spectrum.frequency_resolution();
+        let bins = spectrum
+            .bins
+            .iter()
+            .enumerate()
+            .map(|(k, c)| {
+                let freq = k.min(n - k) as f64 * freq_res;
+                let passes = match filter {
+                    FftFilterType::LowPass(cutoff) => freq <= cutoff,
+                    FftFilterType::HighPass(cutoff) => freq >= cutoff,
+                    FftFilterType::BandPass(low, high) => freq >= low && freq <= high,
+                    FftFilterType::BandStop(low, high) => freq <= low || freq >= high,
+                };
+                if passes { *c } else { Complex::new(0.0, 0.0) }
+            })
+            .collect();
+        ComplexSpectrum::new(bins, spectrum.sample_rate, spectrum.start)
+    }
+
+    /// Apply discrete wavelet transform
     ///
-    /// This function applies the Haar Wavelet Transform to the chart data.
+    /// Decompose the given STANDARD view chart data into `levels` detail
+    /// coefficient sets and the final approximation using an orthogonal wavelet.
+    /// Each level convolves the current approximation with the decomposition
+    /// filters, extending it beyond the boundaries with `mode`, and keeps every
+    /// second sample.
     ///
     /// ---
     ///
     /// * `chart`: The chart data to be transformed.
-    pub fn haar_wavelet_transform(&self, chart: &ChartModel) -> Result<Vec<Point>> {
-        if chart.metadata.transform != ChartTransform::Standard {
+    /// * `wavelet`: The wavelet to use.
+    /// * `levels`: The number of decomposition levels.
+    /// * `mode`: The signal extension mode at the boundaries.
+    pub fn dwt_forward(
+        &self,
+        chart: &ChartModel,
+        wavelet: Wavelet,
+        levels: usize,
+        mode: ExtensionMode,
+    ) -> Result<WaveletDecomposition> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        let low = wavelet.decomposition_low();
+        let high = wavelet.decomposition_high();
+        let max_level = Self::dwt_max_level(chart.data.len(), low.len());
+        if levels == 0 || levels > max_level {
+            return Err(anyhow!(ChartProcessingError::WaveletLevel(max_level)));
+        }
+
+        let mut approximation: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        let mut details = Vec::with_capacity(levels);
+        let mut lengths = Vec::with_capacity(levels);
+        for _ in 0..levels {
+            lengths.push(approximation.len());
+            let output_len = (approximation.len() + low.len() - 1) / 2;
+            let convolve = |filter: &[f64]| -> Vec<f64> {
+                (0..output_len)
+                    .map(|i| {
+                        filter
+                            .iter()
+                            .enumerate()
+                            .map(|(k, h)| {
+                                h * mode.sample(&approximation, (2 * i + 1) as isize - k as isize)
+                            })
+                            .sum()
+                    })
+                    .collect()
+            };
+            details.push(convolve(&high));
+            approximation = convolve(low);
+        }
+
+        Ok(WaveletDecomposition {
+            wavelet,
+            mode,
+            approximation,
+            details,
+            lengths,
+            sample_rate: chart.sample_rate,
+            start: chart.data.first().map(|p| p.x).unwrap_or_default(),
+        })
+    }
+
+    /// Deepest useful decomposition level for the signal and filter lengths.
+    fn dwt_max_level(signal_len: usize, filter_len: usize) -> usize {
+        if filter_len < 2 || signal_len < filter_len - 1 {
+            return 0;
+        }
+        (signal_len / (filter_len - 1)).ilog2() as usize
+    }
+
+    /// Welch power spectral density
+    ///
+    /// Estimate the single-sided PSD of the given STANDARD view chart by averaging
+    /// the periodograms of overlapping windowed segments.
+    /// The result is scaled to signal units squared per Hz using `sample_rate`.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be analysed.
+    /// * `segment_len`: The number of samples in each segment.
+    /// * `overlap`: The overlap between neighbouring segments in percent.
+    /// * `window`: The window applied to each segment.
+    /// * `averaging`: How the segment periodograms are combined.
+    pub fn welch_psd(
+        &mut self,
+        chart: &ChartModel,
+        segment_len: usize,
+        overlap: f64,
+        window: WindowType,
+        averaging: SpectralAveraging,
+    ) -> Result<Vec<Point>> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        let samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        let coefficients = window.coefficients(segment_len);
+        let segments = self
+            .welch_segments(&[&samples], &coefficients, overlap, Some(DetrendType::Mean))?
+            .remove(0);
+
+        let fs = chart.sample_rate as f64;
+        let scale = 1.0 / (fs * coefficients.iter().map(|w| w * w).sum::<f64>());
+        let bins = segment_len / 2 + 1;
+        Ok((0..bins)
+            .map(|k| {
+                let powers: Vec<f64> = segments.iter().map(|s| s[k].norm_sqr()).collect();
+                let mut density = Self::average(powers, averaging) * scale;
+                if k != 0 && !(segment_len.is_multiple_of(2) && k == segment_len / 2) {
+                    density *= 2.0;
+                }
+                Point::new(k as f64 * fs / segment_len as f64, density)
+            })
+            .collect())
+    }
+
+    /// Block averaged spectrum
+    ///
+    /// Split the given STANDARD view chart into acquisition blocks and average
+    /// their single-sided amplitude spectra. Linear and exponential averaging
+    /// combine the block powers (RMS averaging), the exponential weight settling
+    /// at 1/EXPONENTIAL_AVERAGES, while peak hold keeps the largest amplitude.
+    /// The spread between blocks is returned as a min/max band when requested.
+    /// Unlike the Welch estimates the blocks keep their mean, so the DC bin
+    /// holds the signal offset as on a block-synchronous analyzer.
+    /// Without a block length the blocks recorded in the file header are
+    /// averaged, exactly `blocks_received` blocks of `sample_size` samples.
+    ///
+    /// Returns the average, the band, the number of blocks and the number
+    /// of samples left outside of every block.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be analysed.
+    /// * `block_len`: The number of samples in each block, from the header if `None`.
+    /// * `overlap`: The overlap between neighbouring blocks in percent.
+    /// * `window`: The window applied to each block.
+    /// * `averaging`: How the block spectra are combined.
+    /// * `spread`: Whether the min/max band is computed.
+    pub fn block_average(
+        &mut self,
+        chart: &ChartModel,
+        block_len: Option<usize>,
+        overlap: f64,
+        window: WindowType,
+        averaging: BlockAveraging,
+        spread: BlockSpread,
+    ) -> Result<(Vec<Point>, Option<SpreadBand>, usize, usize)> {
+        if !chart.metadata.transform.is_time_domain() {
             return Err(anyhow!(ChartProcessingError::NonStandard));
         }
-        let y: Vec<f32> = chart.data.iter().map(|point| point.y as f32).collect();
+        let mut samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        let block_len = match block_len {
+            Some(block_len) => block_len,
+            None => {
+                let header = chart
+                    .header
+                    .as_ref()
+                    .ok_or_else(|| anyhow!(ChartProcessingError::NoHeader))?;
+                let block_len = header.sample_size as usize;
+                let blocks = header.blocks_received as usize;
+                if blocks == 0 || samples.len() < block_len * blocks {
+                    return Err(anyhow!(ChartProcessingError::HeaderBlocks(
+                        blocks,
+                        block_len,
+                        samples.len()
+                    )));
+                }
+                samples.truncate(block_len * blocks);
+                block_len
+            }
+        };
+        let coefficients = window.coefficients(block_len);
+        let blocks = self
+            .welch_segments(&[&samples], &coefficients, overlap, None)?
+            .remove(0);
+        let averaged = (blocks.len() - 1) * Self::segment_step(block_len, overlap) + block_len;
+
+        let fs = chart.sample_rate as f64;
+        let gain = coefficients.iter().sum::<f64>();
+        let bins = block_len / 2 + 1;
+        let frequency = |k: usize| k as f64 * fs / block_len as f64;
+        let amplitudes: Vec<Vec<f64>> = blocks
+            .iter()
+            .map(|block| {
+                (0..bins)
+                    .map(|k| {
+                        let amplitude = block[k].norm() / gain;
+                        if k != 0 && !(block_len.is_multiple_of(2) && k == block_len / 2) {
+                            amplitude * 2.0
+                        } else {
+                            amplitude
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let bin_values = |k: usize| amplitudes.iter().map(move |block| block[k]);
+        let average = (0..bins)
+            .map(|k| {
+                let value = match averaging {
+                    BlockAveraging::Linear => {
+                        (bin_values(k).map(|a| a * a).sum::<f64>() / blocks.len() as f64).sqrt()
+                    }
+                    BlockAveraging::Exponential => bin_values(k)
+                        .enumerate()
+                        .fold(0.0, |power, (i, a)| {
+                            let weight = 1.0 / (i + 1).min(EXPONENTIAL_AVERAGES) as f64;
+                            power + (a * a - power) * weight
+                        })
+                        .sqrt(),
+                    BlockAveraging::PeakHold => bin_values(k).fold(0.0, f64::max),
+                };
+                Point::new(frequency(k), value)
+            })
+            .collect();
+        let band = (spread == BlockSpread::MinMax).then(|| {
+            SpreadBand::new(
+                (0..bins)
+                    .map(|k| Point::new(frequency(k), bin_values(k).fold(f64::MAX, f64::min)))
+                    .collect(),
+                (0..bins)
+                    .map(|k| Point::new(frequency(k), bin_values(k).fold(0.0, f64::max)))
+                    .collect(),
+            )
+        });
+        Ok((average, band, blocks.len(), chart.data.len() - averaged))
+    }
+
+    /// Fractional-octave band levels
+    ///
+    /// Integrate the Welch PSD of the given STANDARD view chart over base-10
+    /// octave or third-octave bands with IEC 61260 edges, weighting the bins
+    /// that straddle a band edge by their overlap with the band. Only bands at
+    /// least one bin wide and below the Nyquist frequency are kept. Levels
+    /// are in dB relative to one signal unit, the total being the level of
+    /// all kept bands together.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be analysed.
+    /// * `fraction`: The bandwidth as a fraction of an octave.
+    /// * `segment_len`: The number of samples in each PSD segment.
+    pub fn octave_bands(
+        &mut self,
+        chart: &ChartModel,
+        fraction: OctaveFraction,
+        segment_len: usize,
+    ) -> Result<OctaveSpectrum> {
+        let psd = self.welch_psd(
+            chart,
+            segment_len,
+            DEFAULT_WELCH_OVERLAP,
+            WindowType::Hann,
+            SpectralAveraging::Mean,
+        )?;
+        let df = chart.sample_rate as f64 / segment_len as f64;
+        let nyquist = chart.sample_rate as f64 / 2.0;
+        let b = fraction.bands_per_octave();
+        let level = |power: f64| 10.0 * power.max(MIN_AMPLITUDE * MIN_AMPLITUDE).log10();
+
+        let first = ((df / OCTAVE_REFERENCE).log(OCTAVE_RATIO) * b as f64).floor() as i32;
+        let last = ((nyquist / OCTAVE_REFERENCE).log(OCTAVE_RATIO) * b as f64).ceil() as i32;
+        let mut total = 0.0;
+        let bands: Vec<OctaveBand> = (first..=last)
+            .filter_map(|x| {
+                let center = OCTAVE_REFERENCE * OCTAVE_RATIO.powf(x as f64 / b as f64);
+                let edge = OCTAVE_RATIO.powf(1.0 / (2.0 * b as f64));
+                let (lower, upper) = (center / edge, center * edge);
+                if lower < df || upper - lower < df || upper > nyquist {
+                    return None;
+                }
+                let power: f64 = psd
+                    .iter()
+                    .map(|p| {
+                        let overlap = (p.x + df / 2.0).min(upper) - (p.x - df / 2.0).max(lower);
+                        p.y * overlap.max(0.0)
+                    })
+                    .sum();
+                total += power;
+                let third = x * 3 / b;
+                Some(OctaveBand {
+                    nominal: NOMINAL_THIRD_OCTAVES[third.rem_euclid(10) as usize]
+                        * 10f64.powi(third.div_euclid(10) + 3),
+                    center,
+                    lower,
+                    upper,
+                    level: level(power),
+                })
+            })
+            .collect();
+        if bands.is_empty() {
+            return Err(anyhow!(ChartProcessingError::NoOctaveBands));
+        }
+        Ok(OctaveSpectrum::new(bands, level(total)))
+    }
 
-        let mut freq_data: [f32; u32::MAX as usize] = [0.0; u32::MAX as usize];
-        let len_to_copy = y.len().min(u32::MAX as usize);
-        freq_data[..len_to_copy].copy_from_slice(&y[..len_to_copy]);
+    /// Split signals into overlapping segments and transform them.
+    ///
+    /// Every segment has the optional trend removed and is multiplied by the
+    /// window before the FFT is applied. The segments of all signals count
+    /// towards one progress total. Returns the segments of every signal in order.
+    fn welch_segments(
+        &mut self,
+        signals: &[&[f64]],
+        window: &[f64],
+        overlap: f64,
+        detrend: Option<DetrendType>,
+    ) -> Result<Vec<Vec<Vec<Complex<f64>>>>> {
+        let segment_len = window.len();
+        if segment_len == 0 || signals.iter().any(|samples| segment_len > samples.len()) {
+            return Err(anyhow!(ChartProcessingError::SegmentLength));
+        }
+        if !(0.0..100.0).contains(&overlap) {
+            return Err(anyhow!(ChartProcessingError::Overlap));
+        }
+        let step = Self::segment_step(segment_len, overlap);
+        let fft = self.planner.plan_fft_forward(segment_len);
+        let counts: Vec<usize> = signals
+            .iter()
+            .map(|samples| (samples.len() - segment_len) / step + 1)
+            .collect();
+        let total = counts.iter().sum();
+        let mut done = 0;
+        let mut result = Vec::with_capacity(signals.len());
+        for (samples, segments) in signals.iter().zip(counts) {
+            let transformed = (0..segments)
+                .map(|i| {
+                    self.progress.report(done + i, total)?;
+                    let segment = &samples[i * step..i * step + segment_len];
+                    let segment = match detrend {
+                        Some(detrend) => detrend.apply(segment),
+                        None => segment.to_vec(),
+                    };
+                    let mut buffer: Vec<Complex<f64>> = segment
+                        .iter()
+                        .zip(window)
+                        .map(|(y, w)| Complex::new(y * w, 0.0))
+                        .collect();
+                    fft.process(&mut buffer);
+                    Ok(buffer)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            done += segments;
+            result.push(transformed);
+        }
+        Ok(result)
+    }
 
-        let freq_data_ref: &[f32; u32::MAX as usize] = &freq_data;
-        let mut approx = [0.0; (u32::MAX / 2) as usize];
-        let mut detail = [0.0; (u32::MAX / 2) as usize];
+    /// Distance between the starts of neighbouring segments.
+    fn segment_step(segment_len: usize, overlap: f64) -> usize {
+        ((segment_len as f64 * (1.0 - overlap / 100.0)) as usize).max(1)
+    }
 
-        haar_forward_inplace_stack(freq_data_ref, &mut approx[..], &mut detail[..]);
+    /// Magnitude-squared coherence
+    ///
+    /// Estimate how linearly the `output` chart depends on the `input` chart at
+    /// every frequency, from Welch-averaged auto and cross spectra.
+    /// The result lies between 0 and 1.
+    ///
+    /// ---
+    ///
+    /// * `input`: The excitation chart.
+    /// * `output`: The response chart.
+    /// * `segment_len`: The number of samples in each segment.
+    /// * `overlap`: The overlap between neighbouring segments in percent.
+    /// * `window`: The window applied to each segment.
+    pub fn coherence(
+        &mut self,
+        input: &ChartModel,
+        output: &ChartModel,
+        segment_len: usize,
+        overlap: f64,
+        window: WindowType,
+    ) -> Result<Vec<Point>> {
+        let spectra = self.cross_spectra(input, output, segment_len, overlap, window)?;
+        let fs = input.sample_rate as f64;
+        Ok(spectra
+            .iter()
+            .enumerate()
+            .map(|(k, (gxx, gyy, gxy))| {
+                let coherence = gxy.norm_sqr() / (gxx * gyy).max(MIN_AMPLITUDE);
+                Point::new(k as f64 * fs / segment_len as f64, coherence)
+            })
+            .collect())
+    }
 
-        let approx_end_index = y.len().div_ceil(2);
-        let approx = approx
-            .to_vec()
-            .drain(0..approx_end_index)
-            .collect::<Vec<f32>>();
-        Ok(approx
+    /// Frequency response function
+    ///
+    /// Estimate the transfer function from the `input` chart to the `output`
+    /// chart from Welch-averaged auto and cross spectra. H1 minimizes the noise
+    /// on the output, H2 the noise on the input.
+    /// Returns the magnitude in dB and the unwrapped phase in degrees.
+    ///
+    /// ---
+    ///
+    /// * `input`: The excitation chart.
+    /// * `output`: The response chart.
+    /// * `estimator`: The FRF estimator to use.
+    /// * `segment_len`: The number of samples in each segment.
+    /// * `overlap`: The overlap between neighbouring segments in percent.
+    /// * `window`: The window applied to each segment.
+    pub fn frf(
+        &mut self,
+        input: &ChartModel,
+        output: &ChartModel,
+        estimator: FrfEstimator,
+        segment_len: usize,
+        overlap: f64,
+        window: WindowType,
+    ) -> Result<(Vec<Point>, Vec<Point>)> {
+        let spectra = self.cross_spectra(input, output, segment_len, overlap, window)?;
+        let fs = input.sample_rate as f64;
+        let responses: Vec<Complex<f64>> = spectra
+            .iter()
+            .map(|(gxx, gyy, gxy)| match estimator {
+                FrfEstimator::H1 => gxy / gxx.max(MIN_AMPLITUDE),
+                FrfEstimator::H2 => {
+                    *gyy / if gxy.norm() < MIN_AMPLITUDE {
+                        Complex::new(MIN_AMPLITUDE, 0.0)
+                    } else {
+                        gxy.conj()
+                    }
+                }
+            })
+            .collect();
+        let frequency = |k: usize| k as f64 * fs / segment_len as f64;
+        let magnitude = responses
+            .iter()
+            .enumerate()
+            .map(|(k, h)| Point::new(frequency(k), 20.0 * h.norm().max(MIN_AMPLITUDE).log10()))
+            .collect();
+        let mut phases: Vec<f64> = responses.iter().map(|h| h.arg()).collect();
+        Self::unwrap_phase(&mut phases);
+        let phase = phases
             .iter()
             .enumerate()
-            .map(|(i, v)| Point::new(i as f64, *v as f64))
-            .collect::<Vec<Point>>())
+            .map(|(k, phase)| Point::new(frequency(k), phase.to_degrees()))
+            .collect();
+        Ok((magnitude, phase))
+    }
+
+    /// Welch-averaged single-sided auto and cross spectra of two charts.
+    ///
+    /// Returns `(Gxx, Gyy, Gxy)` for every bin, where `Gxy` is the average
+    /// of `conj(X) * Y`. A common scale factor is omitted since it cancels
+    /// in every ratio built from these spectra.
+    fn cross_spectra(
+        &mut self,
+        input: &ChartModel,
+        output: &ChartModel,
+        segment_len: usize,
+        overlap: f64,
+        window: WindowType,
+    ) -> Result<Vec<(f64, f64, Complex<f64>)>> {
+        if !input.metadata.transform.is_time_domain() || !output.metadata.transform.is_time_domain()
+        {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        if input.sample_rate != output.sample_rate {
+            return Err(anyhow!(ChartProcessingError::SampleRateMismatch));
+        }
+        let len = input.data.len().min(output.data.len());
+        let x: Vec<f64> = input.data.iter().take(len).map(|p| p.y).collect();
+        let y: Vec<f64> = output.data.iter().take(len).map(|p| p.y).collect();
+        let coefficients = window.coefficients(segment_len);
+        let segments =
+            self.welch_segments(&[&x, &y], &coefficients, overlap, Some(DetrendType::Mean))?;
+        let (x_segments, y_segments) = (&segments[0], &segments[1]);
+
+        let count = x_segments.len() as f64;
+        Ok((0..segment_len / 2 + 1)
+            .map(|k| {
+                x_segments.iter().zip(y_segments).fold(
+                    (0.0, 0.0, Complex::new(0.0, 0.0)),
+                    |(gxx, gyy, gxy), (xs, ys)| {
+                        (
+                            gxx + xs[k].norm_sqr() / count,
+                            gyy + ys[k].norm_sqr() / count,
+                            gxy + xs[k].conj() * ys[k] / count,
+                        )
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Combine per-segment estimates.
+    ///
+    /// The median is divided by the bias of the median of exponentially
+    /// distributed periodogram values, so both estimators are comparable.
+    fn average(mut values: Vec<f64>, averaging: SpectralAveraging) -> f64 {
+        match averaging {
+            SpectralAveraging::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            SpectralAveraging::Median => {
+                let n = values.len();
+                let median = Self::median(&mut values);
+                let bias = 1.0
+                    + (1..=(n - 1) / 2)
+                        .map(|i| 1.0 / (2 * i + 1) as f64 - 1.0 / (2 * i) as f64)
+                        .sum::<f64>();
+                median / bias
+            }
+        }
+    }
+
+    /// Apply inverse transformation
+    ///
+    /// Rebuild the time signal from the data an FFT, STFT or DWT chart keeps
+    /// about its forward transform.
+    /// DWT coefficient charts contribute their own values to the reconstruction,
+    /// so edits of a single level are taken into account.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The transformed chart.
+    pub fn inverse(&mut self, chart: &ChartModel) -> Result<Vec<Point>> {
+        if let Some(spectrum) = &chart.spectrum {
+            self.fft_inverse(spectrum)
+        } else if let Some(stft) = &chart.stft {
+            self.stft_inverse(stft)
+        } else if let Some(coefficients) = &chart.wavelet {
+            let mut decomposition = (*coefficients.decomposition).clone();
+            let values: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+            let target = match coefficients.band {
+                WaveletBand::Detail(level) => &mut decomposition.details[level - 1],
+                WaveletBand::Approximation => &mut decomposition.approximation,
+            };
+            if target.len() == values.len() {
+                *target = values;
+            }
+            Ok(self.dwt_inverse(&decomposition))
+        } else {
+            Err(anyhow!(ChartProcessingError::NotInvertible))
+        }
+    }
+
+    /// Root mean square difference between a reconstruction and its origin.
+    ///
+    /// ---
+    ///
+    /// * `reconstructed`: The rebuilt time signal.
+    /// * `origin`: The samples the forward transform was computed from.
+    pub fn reconstruction_error(&self, reconstructed: &[Point], origin: &[f64]) -> f64 {
+        let len = reconstructed.len().min(origin.len());
+        if len == 0 {
+            return 0.0;
+        }
+        (reconstructed
+            .iter()
+            .zip(origin)
+            .map(|(p, y)| (p.y - y).powi(2))
+            .sum::<f64>()
+            / len as f64)
+            .sqrt()
+    }
+
+    fn fft_inverse(&mut self, spectrum: &ComplexSpectrum) -> Result<Vec<Point>> {
+        let mut buffer = spectrum.bins.clone();
+        let n = buffer.len();
+        self.progress.begin(1);
+        self.transform(&mut buffer, FftDirection::Inverse)?;
+        let fs = spectrum.sample_rate as f64;
+        Ok(buffer
+            .iter()
+            .enumerate()
+            .map(|(i, c)| Point::new(spectrum.start + i as f64 / fs, c.re / n as f64))
+            .collect())
+    }
+
+    /// Weighted overlap-add reconstruction of centered STFT frames.
+    fn stft_inverse(&mut self, stft: &StftFrames) -> Result<Vec<Point>> {
+        let window_size = stft.window.len();
+        let pad = window_size / 2;
+        let padded_len =
+            ((stft.frames.len() - 1) * stft.hop_size + window_size).max(stft.signal_len + pad);
+        let mut signal = vec![0.0; padded_len];
+        let mut norm = vec![0.0; padded_len];
+        let ifft = self.planner.plan_fft_inverse(window_size);
+        for (m, frame) in stft.frames.iter().enumerate() {
+            self.progress.report(m, stft.frames.len())?;
+            let mut buffer = frame.clone();
+            ifft.process(&mut buffer);
+            for (i, (c, w)) in buffer.iter().zip(&stft.window).enumerate() {
+                signal[m * stft.hop_size + i] += c.re / window_size as f64 * w;
+                norm[m * stft.hop_size + i] += w * w;
+            }
+        }
+        let fs = stft.sample_rate as f64;
+        Ok((0..stft.signal_len)
+            .map(|i| {
+                let weight = norm[i + pad];
+                let y = if weight > f64::EPSILON {
+                    signal[i + pad] / weight
+                } else {
+                    0.0
+                };
+                Point::new(stft.start + i as f64 / fs, y)
+            })
+            .collect())
+    }
+
+    /// Multi-level inverse DWT using the reconstruction filters.
+    fn dwt_inverse(&self, decomposition: &WaveletDecomposition) -> Vec<Point> {
+        let rec_low: Vec<f64> = decomposition
+            .wavelet
+            .decomposition_low()
+            .iter()
+            .rev()
+            .copied()
+            .collect();
+        let rec_high: Vec<f64> = decomposition
+            .wavelet
+            .decomposition_high()
+            .iter()
+            .rev()
+            .copied()
+            .collect();
+        let filter_len = rec_low.len() as isize;
+
+        let mut approximation = decomposition.approximation.clone();
+        for (detail, len) in decomposition
+            .details
+            .iter()
+            .zip(&decomposition.lengths)
+            .rev()
+        {
+            approximation = (0..*len as isize)
+                .map(|n| {
+                    let last = ((n + filter_len - 2) / 2).min(approximation.len() as isize - 1);
+                    (n / 2..=last)
+                        .map(|i| {
+                            let k = (n + filter_len - 2 - 2 * i) as usize;
+                            approximation[i as usize] * rec_low[k]
+                                + detail[i as usize] * rec_high[k]
+                        })
+                        .sum()
+                })
+                .collect();
+        }
+
+        let fs = decomposition.sample_rate as f64;
+        approximation
+            .iter()
+            .enumerate()
+            .map(|(i, y)| Point::new(decomposition.start + i as f64 / fs, *y))
+            .collect()
+    }
+
+    /// Continuous wavelet transform scalogram
+    ///
+    /// Compute the CWT of the given STANDARD view chart over log-spaced scales
+    /// whose pseudo-frequencies span `low..high`, by multiplying the signal
+    /// spectrum with the scaled wavelet spectra.
+    /// The power in dB is returned as a heatmap with time in seconds and
+    /// pseudo-frequency in Hz; the cone of influence is kept as its boundary.
+    /// Long signals are decimated in time to at most `CWT_MAX_COLUMNS` columns.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be transformed.
+    /// * `wavelet`: The mother wavelet.
+    /// * `low`: The lowest pseudo-frequency in Hz.
+    /// * `high`: The highest pseudo-frequency in Hz.
+    /// * `scales`: The number of scales.
+    pub fn cwt(
+        &mut self,
+        chart: &ChartModel,
+        wavelet: ContinuousWavelet,
+        low: f64,
+        high: f64,
+        scales: usize,
+    ) -> Result<Heatmap> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        if chart.data.len() < 2 {
+            return Err(anyhow!(ChartProcessingError::NotEnoughSamples));
+        }
+        let fs = chart.sample_rate as f64;
+        if !(low > 0.0 && low < high && high <= fs / 2.0) || scales < 2 {
+            return Err(anyhow!(ChartProcessingError::FrequencyRange));
+        }
+        let len = chart.data.len();
+        let padded_len = (2 * len).next_power_of_two();
+        let mean = chart.data.iter().map(|p| p.y).sum::<f64>() / len as f64;
+        let mut spectrum = vec![Complex::new(0.0, 0.0); padded_len];
+        chart
+            .data
+            .iter()
+            .zip(spectrum.iter_mut())
+            .for_each(|(p, c)| c.re = p.y - mean);
+        self.transform(&mut spectrum, FftDirection::Forward)?;
+
+        let dt = 1.0 / fs;
+        let omega = |k: usize| {
+            let k = if k <= padded_len / 2 {
+                k as f64
+            } else {
+                k as f64 - padded_len as f64
+            };
+            TAU * k / (padded_len as f64 * dt)
+        };
+        let stride = len.div_ceil(CWT_MAX_COLUMNS);
+        let columns: Vec<usize> = (0..len).step_by(stride).collect();
+        let freqs: Vec<f64> = (0..scales)
+            .map(|j| low * (high / low).powf(j as f64 / (scales - 1) as f64))
+            .collect();
+        let ifft = self.planner.plan_fft_inverse(padded_len);
+        let mut values = vec![Vec::with_capacity(scales); columns.len()];
+        for (j, freq) in freqs.iter().enumerate() {
+            self.progress.report(j, scales)?;
+            let scale = 1.0 / (freq * wavelet.fourier_factor());
+            let norm = (TAU * scale / dt).sqrt();
+            let mut buffer: Vec<Complex<f64>> = spectrum
+                .iter()
+                .enumerate()
+                .map(|(k, c)| c * wavelet.spectrum(scale * omega(k)) * norm)
+                .collect();
+            ifft.process(&mut buffer);
+            for (column, i) in values.iter_mut().zip(&columns) {
+                let power = (buffer[*i] / padded_len as f64).norm_sqr();
+                column.push(10.0 * power.max(MIN_AMPLITUDE).log10());
+            }
+        }
+
+        let start = chart.data.first().map(|p| p.x).unwrap_or_default();
+        let times: Vec<f64> = columns.iter().map(|i| start + *i as f64 * dt).collect();
+        let mut heatmap = Heatmap::new(times.clone(), freqs, values, "dB");
+        heatmap.boundary = columns
+            .iter()
+            .zip(times)
+            .map(|(i, time)| {
+                let edge_distance = ((*i).min(len - 1 - i) as f64 + 1.0) * dt;
+                let max_scale = edge_distance / wavelet.e_folding();
+                let freq = 1.0 / (wavelet.fourier_factor() * max_scale);
+                Point::new(time, freq.clamp(low, high))
+            })
+            .collect();
+        Ok(heatmap)
+    }
+
+    /// Apply Hilbert transform
+    ///
+    /// Build the analytic signal of the given STANDARD view chart and derive
+    /// its envelope, unwrapped instantaneous phase in radians and
+    /// instantaneous frequency in Hz.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be transformed.
+    pub fn hilbert(&mut self, chart: &ChartModel) -> Result<(Vec<Point>, Vec<Point>, Vec<Point>)> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        let samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        self.progress.begin(2);
+        let analytic = self.analytic_signal(&samples)?;
+
+        let mut phases: Vec<f64> = analytic.iter().map(|c| c.arg()).collect();
+        Self::unwrap_phase(&mut phases);
+        let fs = chart.sample_rate as f64;
+        let frequencies: Vec<f64> = (0..phases.len())
+            .map(|i| {
+                let (prev, next) = (i.saturating_sub(1), (i + 1).min(phases.len() - 1));
+                (phases[next] - phases[prev]) / (next - prev).max(1) as f64 * fs / TAU
+            })
+            .collect();
+
+        let points = |values: Vec<f64>| -> Vec<Point> {
+            chart
+                .data
+                .iter()
+                .zip(values)
+                .map(|(p, v)| Point::new(p.x, v))
+                .collect()
+        };
+        Ok((
+            points(analytic.iter().map(|c| c.norm()).collect()),
+            points(phases),
+            points(frequencies),
+        ))
+    }
+
+    /// Analytic signal of real samples
+    ///
+    /// Computed in the frequency domain by suppressing the negative
+    /// frequencies and doubling the positive ones.
+    fn analytic_signal(&mut self, samples: &[f64]) -> Result<Vec<Complex<f64>>> {
+        let n = samples.len();
+        let mut buffer: Vec<Complex<f64>> = samples.iter().map(|y| Complex::new(*y, 0.0)).collect();
+        self.transform(&mut buffer, FftDirection::Forward)?;
+        for (k, c) in buffer.iter_mut().enumerate() {
+            if k == 0 || (n.is_multiple_of(2) && k == n / 2) {
+                continue;
+            }
+            *c *= if k < n.div_ceil(2) { 2.0 } else { 0.0 };
+        }
+        self.transform(&mut buffer, FftDirection::Inverse)?;
+        Ok(buffer.iter().map(|c| c / n as f64).collect())
+    }
+
+    /// Envelope spectrum
+    ///
+    /// Demodulate the given STANDARD view chart for bearing diagnostics:
+    /// band-pass it around the excited resonance, take the Hilbert envelope,
+    /// remove its DC component and compute the single-sided amplitude spectrum.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be analysed.
+    /// * `low`: The lower edge of the demodulation band in Hz.
+    /// * `high`: The upper edge of the demodulation band in Hz.
+    pub fn envelope_spectrum(
+        &mut self,
+        chart: &ChartModel,
+        low: f64,
+        high: f64,
+    ) -> Result<Vec<Point>> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        let fs = chart.sample_rate as f64;
+        if !(low > 0.0 && low < high && high <= fs / 2.0) {
+            return Err(anyhow!(ChartProcessingError::FrequencyRange));
+        }
+        let samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        self.progress.begin(5);
+        let filtered = self.band_pass(&samples, fs, low, high)?;
+        let envelope: Vec<f64> = self
+            .analytic_signal(&filtered)?
+            .iter()
+            .map(|c| c.norm())
+            .collect();
+        let mean = envelope.iter().sum::<f64>() / envelope.len() as f64;
+
+        let mut buffer: Vec<Complex<f64>> = envelope
+            .iter()
+            .map(|y| Complex::new(y - mean, 0.0))
+            .collect();
+        self.transform(&mut buffer, FftDirection::Forward)?;
+        Ok(ComplexSpectrum::new(buffer, chart.sample_rate, 0.0)
+            .one_sided()
+            .iter()
+            .map(|(freq, c)| Point::new(*freq, c.norm()))
+            .collect())
+    }
+
+    /// Zero-phase band-pass filter
+    ///
+    /// Applied in the frequency domain with raised-cosine transitions
+    /// of a tenth of the band width on both edges to limit ringing.
+    fn band_pass(&mut self, samples: &[f64], fs: f64, low: f64, high: f64) -> Result<Vec<f64>> {
+        let n = samples.len();
+        let mut buffer: Vec<Complex<f64>> = samples.iter().map(|y| Complex::new(*y, 0.0)).collect();
+        self.transform(&mut buffer, FftDirection::Forward)?;
+        let transition = (high - low) * 0.1;
+        let gain = |freq: f64| {
+            let distance = if freq < low {
+                low - freq
+            } else if freq > high {
+                freq - high
+            } else {
+                0.0
+            };
+            if distance >= transition {
+                0.0
+            } else {
+                (PI / 2.0 * distance / transition).cos().powi(2)
+            }
+        };
+        for (k, c) in buffer.iter_mut().enumerate() {
+            *c *= gain(k.min(n - k) as f64 * fs / n as f64) / n as f64;
+        }
+        self.transform(&mut buffer, FftDirection::Inverse)?;
+        Ok(buffer.iter().map(|c| c.re).collect())
+    }
+
+    /// Resample chart
+    ///
+    /// Change the sample rate of the given STANDARD view chart by truncating
+    /// or zero padding its spectrum, which band-limits the signal to the new
+    /// Nyquist frequency and prevents aliasing when downsampling.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be resampled.
+    /// * `rate`: The target sample rate in Hz.
+    pub fn resample(&mut self, chart: &ChartModel, rate: f32) -> Result<Vec<Point>> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        if rate <= 0.0 || !rate.is_finite() {
+            return Err(anyhow!(ChartProcessingError::SampleRate));
+        }
+        let n = chart.data.len();
+        let m = ((n as f64 * rate as f64 / chart.sample_rate as f64).round() as usize).max(1);
+        let mut spectrum: Vec<Complex<f64>> =
+            chart.data.iter().map(|p| Complex::new(p.y, 0.0)).collect();
+        self.progress.begin(2);
+        self.transform(&mut spectrum, FftDirection::Forward)?;
+
+        let mut buffer = vec![Complex::new(0.0, 0.0); m];
+        let kept = n.min(m);
+        let positive = kept.div_ceil(2);
+        buffer[..positive].copy_from_slice(&spectrum[..positive]);
+        for k in 1..=kept / 2 {
+            buffer[m - k] = spectrum[n - k];
+        }
+        if kept.is_multiple_of(2) && kept > 0 {
+            if n > m {
+                // Both halves of the old spectrum fold onto the new Nyquist bin
+                buffer[m / 2] = spectrum[m / 2] + spectrum[n - m / 2];
+            } else if n < m {
+                // The old Nyquist bin is split between both halves
+                buffer[n / 2] = spectrum[n / 2] / 2.0;
+                buffer[m - n / 2] = spectrum[n / 2] / 2.0;
+            }
+        }
+        self.transform(&mut buffer, FftDirection::Inverse)?;
+
+        let start = chart.data.first().map(|p| p.x).unwrap_or_default();
+        Ok(buffer
+            .iter()
+            .enumerate()
+            .map(|(i, c)| Point::new(start + i as f64 / rate as f64, c.re / n as f64))
+            .collect())
+    }
+
+    /// Decimate chart
+    ///
+    /// Reduce the sample rate of the given STANDARD view chart by an integer
+    /// factor. The signal is first filtered by a zero-phase Hamming windowed-sinc
+    /// low-pass filter at the new Nyquist frequency, then every `factor`-th
+    /// sample is kept.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be decimated.
+    /// * `factor`: The decimation factor.
+    pub fn decimate(&self, chart: &ChartModel, factor: usize) -> Result<Vec<Point>> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        if factor == 0 {
+            return Err(anyhow!(ChartProcessingError::DecimationFactor));
+        }
+        let samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        let half = (DECIMATION_TAPS_PER_FACTOR * factor / 2) as isize;
+        let cutoff = 1.0 / factor as f64;
+        let window = WindowType::Hamming.coefficients(2 * half as usize + 1);
+        let taps: Vec<f64> = (-half..=half)
+            .zip(&window)
+            .map(|(i, w)| {
+                let sinc = if i == 0 {
+                    1.0
+                } else {
+                    (PI * cutoff * i as f64).sin() / (PI * cutoff * i as f64)
+                };
+                cutoff * sinc * w
+            })
+            .collect();
+        let gain: f64 = taps.iter().sum();
+
+        let start = chart.data.first().map(|p| p.x).unwrap_or_default();
+        let dt = factor as f64 / chart.sample_rate as f64;
+        (0..samples.len())
+            .step_by(factor)
+            .enumerate()
+            .map(|(j, i)| {
+                self.progress.report(i, samples.len())?;
+                let y = (-half..=half)
+                    .zip(&taps)
+                    .map(|(k, h)| h * ExtensionMode::Symmetric.sample(&samples, i as isize + k))
+                    .sum::<f64>()
+                    / gain;
+                Ok(Point::new(start + j as f64 * dt, y))
+            })
+            .collect()
+    }
+
+    /// Crop chart
+    ///
+    /// Keep the samples of the given STANDARD view chart lying between `start`
+    /// and `end` seconds. The samples keep their time stamps, so transforms of
+    /// the segment stay aligned with the original recording.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be cropped.
+    /// * `start`: The segment start time in seconds.
+    /// * `end`: The segment end time in seconds.
+    pub fn crop(&self, chart: &ChartModel, start: f64, end: f64) -> Result<Vec<Point>> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        let segment: Vec<Point> = chart
+            .data
+            .iter()
+            .filter(|p| p.x >= start && p.x <= end)
+            .cloned()
+            .collect();
+        if start >= end || segment.len() < 2 {
+            return Err(anyhow!(ChartProcessingError::CropRange));
+        }
+        Ok(segment)
+    }
+
+    /// Detrend chart
+    ///
+    /// Remove the DC offset, linear drift, polynomial baseline or piecewise
+    /// linear trend from the given STANDARD view chart.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be detrended.
+    /// * `detrend`: The trend model to remove.
+    pub fn detrend(&self, chart: &ChartModel, detrend: DetrendType) -> Result<Vec<Point>> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        let samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        Ok(chart
+            .data
+            .iter()
+            .zip(detrend.apply(&samples))
+            .map(|(p, y)| Point::new(p.x, y))
+            .collect())
+    }
+
+    /// Autocorrelation
+    ///
+    /// Correlate the given STANDARD view chart with itself via zero padded FFT.
+    /// The peak is searched after the first zero crossing, so it reflects the
+    /// dominant period rather than the trivial maximum at zero lag.
+    ///
+    /// Returns the correlation over lags in seconds and the lag of its peak.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be analysed.
+    /// * `scale`: The normalization of the result.
+    pub fn autocorrelation(
+        &mut self,
+        chart: &ChartModel,
+        scale: CorrelationScale,
+    ) -> Result<(Vec<Point>, Option<f64>)> {
+        let points = self.correlate(chart, chart, scale)?;
+        let zero_lag = chart.data.len().saturating_sub(1);
+        let peak = points
+            .iter()
+            .skip(zero_lag)
+            .position(|p| p.y <= 0.0)
+            .and_then(|crossing| {
+                points[zero_lag + crossing..]
+                    .iter()
+                    .max_by(|a, b| a.y.total_cmp(&b.y))
+            })
+            .map(|p| p.x);
+        Ok((points, peak))
+    }
+
+    /// Cross-correlation
+    ///
+    /// Correlate two STANDARD view charts via zero padded FFT. A positive
+    /// lag means that `first` is delayed relative to `second`.
+    ///
+    /// Returns the correlation over lags in seconds and the lag of its peak.
+    ///
+    /// ---
+    ///
+    /// * `first`: The delayed chart.
+    /// * `second`: The reference chart.
+    /// * `scale`: The normalization of the result.
+    pub fn cross_correlation(
+        &mut self,
+        first: &ChartModel,
+        second: &ChartModel,
+        scale: CorrelationScale,
+    ) -> Result<(Vec<Point>, Option<f64>)> {
+        let points = self.correlate(first, second, scale)?;
+        let peak = points
+            .iter()
+            .max_by(|a, b| a.y.total_cmp(&b.y))
+            .map(|p| p.x);
+        Ok((points, peak))
+    }
+
+    fn correlate(
+        &mut self,
+        first: &ChartModel,
+        second: &ChartModel,
+        scale: CorrelationScale,
+    ) -> Result<Vec<Point>> {
+        if !first.metadata.transform.is_time_domain() || !second.metadata.transform.is_time_domain()
+        {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        if first.sample_rate != second.sample_rate {
+            return Err(anyhow!(ChartProcessingError::SampleRateMismatch));
+        }
+        let a: Vec<f64> = first.data.iter().map(|p| p.y).collect();
+        let b: Vec<f64> = second.data.iter().map(|p| p.y).collect();
+        let (n_a, n_b) = (a.len(), b.len());
+        if n_a == 0 || n_b == 0 {
+            return Ok(Vec::new());
+        }
+        let len = (n_a + n_b - 1).next_power_of_two();
+        let padded = |samples: &[f64]| -> Vec<Complex<f64>> {
+            samples
+                .iter()
+                .map(|y| Complex::new(*y, 0.0))
+                .chain(std::iter::repeat(Complex::new(0.0, 0.0)))
+                .take(len)
+                .collect()
+        };
+        let (mut spectrum_a, mut spectrum_b) = (padded(&a), padded(&b));
+        self.progress.begin(3);
+        self.transform(&mut spectrum_a, FftDirection::Forward)?;
+        self.transform(&mut spectrum_b, FftDirection::Forward)?;
+        let mut buffer: Vec<Complex<f64>> = spectrum_a
+            .iter()
+            .zip(&spectrum_b)
+            .map(|(x, y)| x * y.conj())
+            .collect();
+        self.transform(&mut buffer, FftDirection::Inverse)?;
+
+        let energy = (a.iter().map(|y| y * y).sum::<f64>() * b.iter().map(|y| y * y).sum::<f64>())
+            .sqrt()
+            .max(MIN_AMPLITUDE);
+        let dt = 1.0 / first.sample_rate as f64;
+        let lags = -(n_b as isize - 1)..n_a as isize;
+        Ok(lags
+            .map(|lag| {
+                let raw = buffer[lag.rem_euclid(len as isize) as usize].re / len as f64;
+                let overlap = (n_b as isize).min(n_a as isize - lag) - 0.max(-lag);
+                let value = match scale {
+                    CorrelationScale::None => raw,
+                    CorrelationScale::Biased => raw / n_a.max(n_b) as f64,
+                    CorrelationScale::Unbiased => raw / overlap.max(1) as f64,
+                    CorrelationScale::Coefficient => raw / energy,
+                };
+                Point::new(lag as f64 * dt, value)
+            })
+            .collect())
+    }
+
+    /// Find spectral peaks
+    ///
+    /// Locate the local maxima of a spectrum chart which are at least
+    /// `threshold` high, `distance` Hz apart and `prominence` above the
+    /// surrounding baseline. The frequency and amplitude of every peak are
+    /// refined by fitting a parabola (or a Gaussian) through the neighbouring
+    /// bins, and peaks forming a harmonic series are numbered.
+    /// At most `MAX_SPECTRAL_PEAKS` of the highest peaks are returned,
+    /// ordered by frequency.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The spectrum chart to be analysed.
+    /// * `prominence`: The minimal height of a peak above its baseline.
+    /// * `distance`: The minimal distance between neighbouring peaks in Hz.
+    /// * `threshold`: The minimal height of a peak.
+    /// * `interpolation`: The model used to refine the peak position.
+    pub fn find_peaks(
+        &self,
+        chart: &ChartModel,
+        prominence: f64,
+        distance: f64,
+        threshold: f64,
+        interpolation: PeakInterpolation,
+    ) -> Result<Vec<SpectralPeak>> {
+        if !chart.metadata.transform.is_spectrum() {
+            return Err(anyhow!(ChartProcessingError::NotSpectrum));
+        }
+        let points: Vec<Point> = match &chart.spectrum {
+            Some(spectrum) => spectrum
+                .one_sided()
+                .iter()
+                .map(|(freq, c)| Point::new(*freq, c.norm()))
+                .collect(),
+            None => chart.data.clone(),
+        };
+        let y: Vec<f64> = points.iter().map(|p| p.y).collect();
+
+        let mut candidates: Vec<usize> = (1..y.len().saturating_sub(1))
+            .filter(|&i| y[i] > y[i - 1] && y[i] >= y[i + 1] && y[i] >= threshold)
+            .collect();
+        candidates.sort_by(|a, b| y[*b].total_cmp(&y[*a]));
+        let mut accepted: Vec<(usize, f64)> = Vec::new();
+        for i in candidates {
+            if accepted
+                .iter()
+                .any(|(j, _)| (points[i].x - points[*j].x).abs() < distance)
+            {
+                continue;
+            }
+            // The baseline is the higher of the two minima reached before
+            // the signal rises above the peak on either side
+            let left = y[..i]
+                .iter()
+                .rev()
+                .take_while(|v| **v <= y[i])
+                .fold(y[i], |acc, v| acc.min(*v));
+            let right = y[i + 1..]
+                .iter()
+                .take_while(|v| **v <= y[i])
+                .fold(y[i], |acc, v| acc.min(*v));
+            let peak_prominence = y[i] - left.max(right);
+            if peak_prominence >= prominence {
+                accepted.push((i, peak_prominence));
+            }
+            if accepted.len() == MAX_SPECTRAL_PEAKS {
+                break;
+            }
+        }
+
+        let mut peaks: Vec<SpectralPeak> = accepted
+            .into_iter()
+            .map(|(i, peak_prominence)| {
+                let (a, b, c) = (y[i - 1], y[i], y[i + 1]);
+                let (a, b, c, logarithmic) =
+                    if interpolation == PeakInterpolation::Gaussian && a > 0.0 && c > 0.0 {
+                        (a.ln(), b.ln(), c.ln(), true)
+                    } else {
+                        (a, b, c, false)
+                    };
+                let curvature = a - 2.0 * b + c;
+                let delta = if curvature.abs() > MIN_AMPLITUDE {
+                    (0.5 * (a - c) / curvature).clamp(-0.5, 0.5)
+                } else {
+                    0.0
+                };
+                let height = b - 0.25 * (a - c) * delta;
+                let step = (points[i + 1].x - points[i - 1].x) / 2.0;
+                SpectralPeak::new(
+                    points[i].x + delta * step,
+                    if logarithmic { height.exp() } else { height },
+                    peak_prominence,
+                )
+            })
+            .collect();
+        peaks.sort_by(|a, b| a.frequency.total_cmp(&b.frequency));
+        Self::group_harmonics(&mut peaks);
+        Ok(peaks)
+    }
+
+    /// Number the peaks forming the longest harmonic series.
+    ///
+    /// Every peak is tried as the fundamental; the one explaining the most
+    /// peaks as its integer multiples wins, ties go to the stronger fundamental.
+    fn group_harmonics(peaks: &mut [SpectralPeak]) {
+        let harmonic_of = |fundamental: f64, frequency: f64| {
+            let order = (frequency / fundamental).round();
+            let error = (frequency - order * fundamental).abs();
+            (order >= 1.0 && error <= HARMONIC_TOLERANCE * order * fundamental)
+                .then_some(order as usize)
+        };
+        let best = peaks
+            .iter()
+            .filter(|peak| peak.frequency > 0.0)
+            .map(|fundamental| {
+                let count = peaks
+                    .iter()
+                    .filter(|peak| harmonic_of(fundamental.frequency, peak.frequency).is_some())
+                    .count();
+                (fundamental.frequency, fundamental.amplitude, count)
+            })
+            .max_by(|a, b| a.2.cmp(&b.2).then(a.1.total_cmp(&b.1)));
+        let Some((fundamental, _, count)) = best else {
+            return;
+        };
+        if count < 2 {
+            return;
+        }
+        for peak in peaks.iter_mut() {
+            peak.harmonic = harmonic_of(fundamental, peak.frequency);
+        }
+    }
+
+    /// Signal statistics
+    ///
+    /// Compute the condition indicators of the chart values, optionally only
+    /// of the points whose x coordinate lies within `range`. Kurtosis is not
+    /// reduced by 3, so a Gaussian signal gives 3. The Vibric header extremes
+    /// are passed through for comparison.
+    /// Returns `None` when no point falls into the range.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be described.
+    /// * `range`: The optional `(x_min, x_max)` interval to restrict the statistics to.
+    pub fn statistics(
+        &self,
+        chart: &ChartModel,
+        range: Option<(f64, f64)>,
+    ) -> Option<SignalStatistics> {
+        let values: Vec<f64> = chart
+            .data
+            .iter()
+            .filter(|p| range.is_none_or(|(low, high)| p.x >= low && p.x <= high))
+            .map(|p| p.y)
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let central_moment =
+            |order: i32| values.iter().map(|y| (y - mean).powi(order)).sum::<f64>() / n;
+        let variance = central_moment(2);
+        let std = variance.sqrt();
+        let rms = (values.iter().map(|y| y * y).sum::<f64>() / n).sqrt();
+        let (min, max) = values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
+                (min.min(*y), max.max(*y))
+            });
+        let peak = min.abs().max(max.abs());
+        let (kurtosis, skewness) = if variance > MIN_AMPLITUDE {
+            (
+                central_moment(4) / variance.powi(2),
+                central_moment(3) / variance.powf(1.5),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        Some(SignalStatistics {
+            count: values.len(),
+            mean,
+            rms,
+            std,
+            peak,
+            peak_to_peak: max - min,
+            crest_factor: if rms > MIN_AMPLITUDE { peak / rms } else { 0.0 },
+            kurtosis,
+            skewness,
+            header_max: chart.header.as_ref().map(|header| header.max_value),
+            header_min: chart.header.as_ref().map(|header| header.min_value),
+        })
+    }
+
+    /// Real cepstrum
+    ///
+    /// Compute the inverse FFT of the natural logarithm of the amplitude
+    /// spectrum of the given STANDARD view chart. The full, even sequence
+    /// is kept so it can be liftered and transformed back.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be analysed.
+    pub fn cepstrum(&mut self, chart: &ChartModel) -> Result<Cepstrum> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        let n = chart.data.len();
+        let mut buffer: Vec<Complex<f64>> =
+            chart.data.iter().map(|p| Complex::new(p.y, 0.0)).collect();
+        self.progress.begin(2);
+        self.transform(&mut buffer, FftDirection::Forward)?;
+        buffer
+            .iter_mut()
+            .for_each(|c| *c = Complex::new(c.norm().max(MIN_AMPLITUDE).ln(), 0.0));
+        self.transform(&mut buffer, FftDirection::Inverse)?;
+        Ok(Cepstrum::new(
+            buffer.iter().map(|c| c.re / n as f64).collect(),
+            chart.sample_rate,
+        ))
+    }
+
+    /// Cepstrum points
+    ///
+    /// Convert a real cepstrum into chart points over quefrency in seconds,
+    /// up to half of the sequence length. The power cepstrum is the squared
+    /// inverse FFT of the log power spectrum, which equals `4 * c^2`.
+    ///
+    /// ---
+    ///
+    /// * `cepstrum`: The real cepstrum to convert.
+    /// * `cepstrum_type`: Whether the real or the power cepstrum is shown.
+    pub fn cepstrum_points(&self, cepstrum: &Cepstrum, cepstrum_type: CepstrumType) -> Vec<Point> {
+        let n = cepstrum.coefficients.len();
+        cepstrum.coefficients[..n.div_ceil(2)]
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let value = match cepstrum_type {
+                    CepstrumType::Real => *c,
+                    CepstrumType::Power => 4.0 * c * c,
+                };
+                Point::new(cepstrum.quefrency(i), value)
+            })
+            .collect()
+    }
+
+    /// Lifter cepstrum
+    ///
+    /// Apply a lifter to the real cepstrum attached to a cepstrum chart and
+    /// transform it back into a single-sided amplitude spectrum.
+    /// The short-pass lifter keeps the quefrencies below `quefrency` (the
+    /// spectral envelope), the long-pass lifter keeps the ones above it, and
+    /// the comb lifter removes the rahmonics of `quefrency`, suppressing the
+    /// matching family of harmonics or sidebands.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The cepstrum chart holding the real cepstrum.
+    /// * `lifter`: The lifter to apply.
+    /// * `quefrency`: The cut-off or comb quefrency in seconds, between one
+    ///   sample and the highest quefrency, half the cepstrum length, since the
+    ///   upper half of the cepstrum mirrors the lower one.
+    pub fn lifter(
+        &mut self,
+        chart: &ChartModel,
+        lifter: LifterType,
+        quefrency: f64,
+    ) -> Result<Vec<Point>> {
+        let cepstrum = chart
+            .cepstrum
+            .as_ref()
+            .ok_or(anyhow!(ChartProcessingError::NoCepstrum))?;
+        let n = cepstrum.coefficients.len();
+        let fs = cepstrum.sample_rate as f64;
+        let period = quefrency * fs;
+        let highest = (n / 2) as f64;
+        if !(1.0..=highest).contains(&period) {
+            return Err(anyhow!(ChartProcessingError::Quefrency(
+                1.0 / fs,
+                highest / fs
+            )));
+        }
+        let mut buffer: Vec<Complex<f64>> = cepstrum
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let q = cepstrum.quefrency(i);
+                let keep = match lifter {
+                    LifterType::ShortPass => q < quefrency,
+                    LifterType::LongPass => q >= quefrency || i == 0,
+                    LifterType::Comb => {
+                        let samples = q * fs;
+                        let order = (samples / period).round();
+                        order < 1.0 || (samples - order * period).abs() > 1.0
+                    }
+                };
+                Complex::new(if keep { *c } else { 0.0 }, 0.0)
+            })
+            .collect();
+        self.progress.begin(1);
+        self.transform(&mut buffer, FftDirection::Forward)?;
+        Ok(buffer[..n.div_ceil(2)]
+            .iter()
+            .enumerate()
+            .map(|(k, c)| Point::new(k as f64 * fs / n as f64, c.re.exp() / n as f64 * 2.0))
+            .collect())
+    }
+
+    /// Integrate or differentiate chart
+    ///
+    /// Apply `passes` integrations (negative `passes`) or differentiations
+    /// (positive `passes`) to the given STANDARD view chart.
+    /// In the frequency domain the spectrum is multiplied by `(jω)^passes`
+    /// and, when integrating, the bins below `cutoff` are zeroed to avoid
+    /// drift. In the time domain the trapezoidal rule is used and every
+    /// integration pass is followed by a linear detrend, while derivatives
+    /// use central differences. Before integrating, the mean of the input is
+    /// removed in both domains, since an offset would grow without bound.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be processed.
+    /// * `passes`: The signed number of passes, negative for integration.
+    /// * `method`: Whether the frequency or the time domain is used.
+    /// * `cutoff`: The low-frequency cutoff of the integration in Hz.
+    pub fn calculus(
+        &mut self,
+        chart: &ChartModel,
+        passes: i32,
+        method: CalculusMethod,
+        cutoff: f64,
+    ) -> Result<Vec<Point>> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        let fs = chart.sample_rate as f64;
+        let mut samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        if passes < 0 {
+            samples = DetrendType::Mean.apply(&samples);
+        }
+        let n = samples.len();
+        match method {
+            CalculusMethod::Frequency => {
+                let mut buffer: Vec<Complex<f64>> =
+                    samples.iter().map(|y| Complex::new(*y, 0.0)).collect();
+                self.progress.begin(2);
+                self.transform(&mut buffer, FftDirection::Forward)?;
+                for (k, c) in buffer.iter_mut().enumerate() {
+                    let frequency = if k <= n / 2 { k } else { n - k } as f64 * fs / n as f64;
+                    let signed = if k <= n / 2 { frequency } else { -frequency };
+                    let nyquist = n.is_multiple_of(2) && k == n / 2;
+                    if k == 0 || nyquist || (passes < 0 && frequency < cutoff) {
+                        *c = Complex::new(0.0, 0.0);
+                    } else {
+                        *c *= Complex::new(0.0, TAU * signed).powi(passes);
+                    }
+                }
+                self.transform(&mut buffer, FftDirection::Inverse)?;
+                samples = buffer.iter().map(|c| c.re / n as f64).collect();
+            }
+            CalculusMethod::Time => {
+                let dt = 1.0 / fs;
+                for pass in 0..passes.unsigned_abs() {
+                    self.progress
+                        .report(pass as usize, passes.unsigned_abs() as usize)?;
+                    samples = if passes < 0 {
+                        let mut sum = 0.0;
+                        let integral: Vec<f64> = std::iter::once(0.0)
+                            .chain(samples.windows(2).map(|pair| {
+                                sum += (pair[0] + pair[1]) * dt / 2.0;
+                                sum
+                            }))
+                            .collect();
+                        DetrendType::Linear.apply(&integral)
+                    } else {
+                        (0..n)
+                            .map(|i| {
+                                let (low, high) = (i.saturating_sub(1), (i + 1).min(n - 1));
+                                (samples[high] - samples[low]) / ((high - low).max(1) as f64 * dt)
+                            })
+                            .collect()
+                    };
+                }
+            }
+        }
+        Ok(chart
+            .data
+            .iter()
+            .zip(samples)
+            .map(|(p, y)| Point::new(p.x, y))
+            .collect())
+    }
+
+    /// Chart arithmetic
+    ///
+    /// Combine two charts of the same transform point by point. When both
+    /// charts share the sample grid the values are paired exactly, otherwise
+    /// `right` is linearly interpolated at the x coordinates of `left` and
+    /// the points outside of its range are dropped.
+    /// Non-finite results, e.g. from a division by zero, are dropped as well.
+    ///
+    /// ---
+    ///
+    /// * `left`: The left operand, defining the x coordinates of the result.
+    /// * `right`: The right operand.
+    /// * `operation`: The operation to apply.
+    pub fn chart_arithmetic(
+        &self,
+        left: &ChartModel,
+        right: &ChartModel,
+        operation: ArithmeticOperation,
+    ) -> Result<Vec<Point>> {
+        if left.metadata.transform != right.metadata.transform {
+            return Err(anyhow!(ChartProcessingError::TransformMismatch));
+        }
+        Ok(left
+            .data
+            .iter()
+            .zip(Self::aligned_values(&left.data, &right.data))
+            .filter_map(|(p, value)| {
+                let y = operation.apply(p.y, value?);
+                y.is_finite().then(|| Point::new(p.x, y))
+            })
+            .collect())
+    }
+
+    /// Scalar arithmetic
+    ///
+    /// Apply the operation to every value of the chart with a constant
+    /// right operand. Non-finite results are dropped.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The left operand.
+    /// * `value`: The constant right operand.
+    /// * `operation`: The operation to apply.
+    pub fn scalar_arithmetic(
+        &self,
+        chart: &ChartModel,
+        value: f64,
+        operation: ArithmeticOperation,
+    ) -> Vec<Point> {
+        chart
+            .data
+            .iter()
+            .filter_map(|p| {
+                let y = operation.apply(p.y, value);
+                y.is_finite().then(|| Point::new(p.x, y))
+            })
+            .collect()
+    }
+
+    /// Values of `points` at the x coordinates of `grid`.
+    ///
+    /// The values are paired exactly when both share the sample grid and
+    /// linearly interpolated otherwise, `None` outside of the range of `points`.
+    fn aligned_values(grid: &[Point], points: &[Point]) -> Vec<Option<f64>> {
+        let same_grid = grid.len() == points.len()
+            && grid
+                .iter()
+                .zip(points)
+                .all(|(a, b)| (a.x - b.x).abs() <= f64::EPSILON * a.x.abs().max(1.0));
+        if same_grid {
+            return points.iter().map(|p| Some(p.y)).collect();
+        }
+        grid.iter()
+            .map(|p| Self::interpolate(points, p.x))
+            .collect()
+    }
+
+    /// Linearly interpolate points sorted by x, `None` outside of their range.
+    fn interpolate(points: &[Point], x: f64) -> Option<f64> {
+        let index = points.partition_point(|p| p.x < x);
+        let right = points.get(index)?;
+        if right.x == x {
+            return Some(right.y);
+        }
+        let left = points.get(index.checked_sub(1)?)?;
+        let t = (x - left.x) / (right.x - left.x);
+        Some(left.y + (right.y - left.y) * t)
+    }
+
+    /// Evaluate expression
+    ///
+    /// Compute the expression at every x coordinate of the `grid` chart,
+    /// with `t` bound to the x coordinate and every referenced chart aligned
+    /// to the grid. Points where a referenced chart is out of range or the
+    /// result is not finite are dropped.
+    ///
+    /// ---
+    ///
+    /// * `expression`: The parsed expression.
+    /// * `grid`: The chart providing the x coordinates of the result.
+    /// * `charts`: The referenced charts paired with their explorer index.
+    pub fn evaluate_expression(
+        &self,
+        expression: &Expression,
+        grid: &ChartModel,
+        charts: &[(usize, &ChartModel)],
+    ) -> Vec<Point> {
+        let aligned: Vec<(usize, Vec<Option<f64>>)> = charts
+            .iter()
+            .map(|(index, chart)| (*index, Self::aligned_values(&grid.data, &chart.data)))
+            .collect();
+        grid.data
+            .iter()
+            .enumerate()
+            .filter_map(|(i, p)| {
+                if aligned.iter().any(|(_, values)| values[i].is_none()) {
+                    return None;
+                }
+                let value_of = |index: usize| {
+                    aligned
+                        .iter()
+                        .find(|(chart, _)| *chart == index)
+                        .and_then(|(_, values)| values[i])
+                        .unwrap_or(f64::NAN)
+                };
+                let y = expression.evaluate(p.x, &value_of);
+                y.is_finite().then(|| Point::new(p.x, y))
+            })
+            .collect()
+    }
+
+    /// Smooth chart
+    ///
+    /// Smooth the given STANDARD view chart with a centered moving average,
+    /// median or Savitzky-Golay filter over `window` samples, the signal being
+    /// extended past its edges with `mode`. The exponential moving average is
+    /// causal with the smoothing factor `2 / (window + 1)` and starts from the
+    /// first sample, so it needs no extension.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be smoothed.
+    /// * `smoothing`: The smoothing filter.
+    /// * `window`: The odd filter length in samples.
+    /// * `order`: The polynomial order of the Savitzky-Golay filter.
+    /// * `mode`: How the signal is extended past its edges.
+    pub fn smooth(
+        &self,
+        chart: &ChartModel,
+        smoothing: SmoothingType,
+        window: usize,
+        order: usize,
+        mode: ExtensionMode,
+    ) -> Result<Vec<Point>> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        if window.is_multiple_of(2) || window > chart.data.len() {
+            return Err(anyhow!(ChartProcessingError::SmoothingWindow));
+        }
+        let samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        let half = (window / 2) as isize;
+        let values = &samples;
+        let neighbourhood =
+            |i: usize| (-half..=half).map(move |k| mode.sample(values, i as isize + k));
+        let step = |i: usize| self.progress.report(i, samples.len());
+        let smoothed: Vec<f64> = match smoothing {
+            SmoothingType::MovingAverage => (0..samples.len())
+                .map(|i| {
+                    step(i)?;
+                    Ok(neighbourhood(i).sum::<f64>() / window as f64)
+                })
+                .collect::<Result<_>>()?,
+            SmoothingType::ExponentialMovingAverage => {
+                let alpha = 2.0 / (window as f64 + 1.0);
+                let mut state = samples.first().copied().unwrap_or_default();
+                samples
+                    .iter()
+                    .map(|y| {
+                        state += alpha * (y - state);
+                        state
+                    })
+                    .collect()
+            }
+            SmoothingType::Median => (0..samples.len())
+                .map(|i| {
+                    step(i)?;
+                    let mut values: Vec<f64> = neighbourhood(i).collect();
+                    values.sort_by(|a, b| a.total_cmp(b));
+                    Ok(values[window / 2])
+                })
+                .collect::<Result<_>>()?,
+            SmoothingType::SavitzkyGolay => {
+                if order >= window {
+                    return Err(anyhow!(ChartProcessingError::PolynomialOrder));
+                }
+                let coefficients = Self::savitzky_golay(window, order);
+                (0..samples.len())
+                    .map(|i| {
+                        step(i)?;
+                        Ok(neighbourhood(i)
+                            .zip(&coefficients)
+                            .map(|(y, c)| y * c)
+                            .sum())
+                    })
+                    .collect::<Result<_>>()?
+            }
+        };
+        Ok(chart
+            .data
+            .iter()
+            .zip(smoothed)
+            .map(|(p, y)| Point::new(p.x, y))
+            .collect())
+    }
+
+    /// Savitzky-Golay smoothing coefficients.
+    ///
+    /// Least squares fit of a polynomial over the window, evaluated at its
+    /// center. The abscissa is scaled to [-1, 1] for conditioning.
+    fn savitzky_golay(window: usize, order: usize) -> Vec<f64> {
+        let half = (window / 2) as isize;
+        let scale = half.max(1) as f64;
+        let abscissa: Vec<f64> = (-half..=half).map(|k| k as f64 / scale).collect();
+        let size = order + 1;
+        let system: Vec<Vec<f64>> = (0..size)
+            .map(|row| {
+                (0..size)
+                    .map(|col| abscissa.iter().map(|x| x.powi((row + col) as i32)).sum())
+                    .chain(std::iter::once(if row == 0 { 1.0 } else { 0.0 }))
+                    .collect()
+            })
+            .collect();
+        let solution = least_squares::solve(system);
+        abscissa
+            .iter()
+            .map(|x| {
+                solution
+                    .iter()
+                    .rev()
+                    .fold(0.0, |acc, coefficient| acc * x + coefficient)
+            })
+            .collect()
+    }
+
+    /// Detect outliers
+    ///
+    /// Flag the samples of the given STANDARD view chart that deviate from the
+    /// center of the signal by more than `threshold` spreads. The Hampel filter
+    /// compares each sample with the median and the scaled median absolute
+    /// deviation of its `window` neighbourhood, the z-score detector with the
+    /// global mean and standard deviation and the MAD detector with the global
+    /// median and scaled median absolute deviation.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be inspected.
+    /// * `detector`: The outlier detector.
+    /// * `threshold`: The number of spreads a sample may deviate by.
+    /// * `window`: The odd Hampel filter length in samples.
+    pub fn detect_outliers(
+        &self,
+        chart: &ChartModel,
+        detector: OutlierDetector,
+        threshold: f64,
+        window: usize,
+    ) -> Result<Vec<bool>> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        let samples: Vec<f64> = chart.data.iter().map(|p| p.y).collect();
+        let robust_center = |values: Vec<f64>| {
+            let mut values = values;
+            let center = Self::median(&mut values);
+            let mut deviations: Vec<f64> = values.iter().map(|y| (y - center).abs()).collect();
+            (center, MAD_SCALE * Self::median(&mut deviations))
+        };
+        let flagged = match detector {
+            OutlierDetector::Hampel => {
+                if window.is_multiple_of(2) || window > samples.len() {
+                    return Err(anyhow!(ChartProcessingError::SmoothingWindow));
+                }
+                let half = (window / 2) as isize;
+                samples
+                    .iter()
+                    .enumerate()
+                    .map(|(i, y)| {
+                        self.progress.report(i, samples.len())?;
+                        let (center, spread) = robust_center(
+                            (-half..=half)
+                                .map(|k| ExtensionMode::Symmetric.sample(&samples, i as isize + k))
+                                .collect(),
+                        );
+                        Ok((y - center).abs() > threshold * spread)
+                    })
+                    .collect::<Result<_>>()?
+            }
+            OutlierDetector::ZScore => {
+                let n = samples.len().max(1) as f64;
+                let mean = samples.iter().sum::<f64>() / n;
+                let std = (samples.iter().map(|y| (y - mean).powi(2)).sum::<f64>() / n).sqrt();
+                samples
+                    .iter()
+                    .map(|y| (y - mean).abs() > threshold * std)
+                    .collect()
+            }
+            OutlierDetector::Mad => {
+                let (center, spread) = robust_center(samples.clone());
+                samples
+                    .iter()
+                    .map(|y| (y - center).abs() > threshold * spread)
+                    .collect()
+            }
+        };
+        Ok(flagged)
+    }
+
+    /// Detect clipping
+    ///
+    /// Flag runs of at least `min_run` samples of the given STANDARD view chart
+    /// lying within `tolerance` percent of the full range from the saturation
+    /// levels. The levels are the header extremes of the recording when the
+    /// chart keeps them, otherwise the extremes of the chart itself.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be inspected.
+    /// * `tolerance`: The distance to the saturation levels in percent of the range.
+    /// * `min_run`: The shortest plateau to be flagged.
+    pub fn detect_clipping(
+        &self,
+        chart: &ChartModel,
+        tolerance: f64,
+        min_run: usize,
+    ) -> Result<Vec<bool>> {
+        if !chart.metadata.transform.is_time_domain() {
+            return Err(anyhow!(ChartProcessingError::NonStandard));
+        }
+        let (max, min) = match &chart.header {
+            Some(header) => (header.max_value as f64, header.min_value as f64),
+            None => (
+                chart.data.iter().map(|p| p.y).fold(f64::MIN, f64::max),
+                chart.data.iter().map(|p| p.y).fold(f64::MAX, f64::min),
+            ),
+        };
+        let margin = (max - min).abs() * tolerance / 100.0;
+        let level = |y: f64| {
+            if y >= max - margin {
+                1
+            } else if y <= min + margin {
+                -1
+            } else {
+                0
+            }
+        };
+
+        let mut flagged = vec![false; chart.data.len()];
+        let mut start = 0;
+        while start < chart.data.len() {
+            let current = level(chart.data[start].y);
+            let end = chart.data[start..]
+                .iter()
+                .position(|p| level(p.y) != current)
+                .map_or(chart.data.len(), |len| start + len);
+            if current != 0 && end - start >= min_run.max(1) {
+                flagged[start..end].fill(true);
+            }
+            start = end;
+        }
+        Ok(flagged)
+    }
+
+    /// Repair samples
+    ///
+    /// Replace the flagged samples by linear interpolation between the nearest
+    /// valid neighbours, hold the last valid value or drop them. Flagged
+    /// samples at the chart edges take the nearest valid value.
+    /// Dropping samples leaves gaps in x, so the result is no longer sampled
+    /// at `sample_rate` and must not be passed to the spectral transforms.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart data to be repaired.
+    /// * `flagged`: The samples to be replaced.
+    /// * `mode`: The repair mode.
+    pub fn repair(
+        &self,
+        chart: &ChartModel,
+        flagged: &[bool],
+        mode: RepairMode,
+    ) -> Result<Vec<Point>> {
+        let valid: Vec<usize> = (0..chart.data.len()).filter(|&i| !flagged[i]).collect();
+        if valid.is_empty() {
+            return Err(anyhow!(ChartProcessingError::NoValidSamples));
+        }
+        if mode == RepairMode::Remove {
+            return Ok(valid.iter().map(|&i| chart.data[i].clone()).collect());
+        }
+
+        let mut next = 0;
+        Ok(chart
+            .data
+            .iter()
+            .enumerate()
+            .map(|(i, point)| {
+                if !flagged[i] {
+                    return point.clone();
+                }
+                while next < valid.len() && valid[next] < i {
+                    next += 1;
+                }
+                let before = next.checked_sub(1).map(|j| &chart.data[valid[j]]);
+                let after = valid.get(next).map(|&j| &chart.data[j]);
+                let y = match (before, after, mode) {
+                    (Some(before), Some(after), RepairMode::Interpolate) => {
+                        before.y
+                            + (after.y - before.y) * (point.x - before.x) / (after.x - before.x)
+                    }
+                    (Some(before), _, _) => before.y,
+                    (None, Some(after), _) => after.y,
+                    (None, None, _) => point.y,
+                };
+                Point::new(point.x, y)
+            })
+            .collect())
+    }
+
+    /// Median of the values, reordering them in place.
+    fn median(values: &mut [f64]) -> f64 {
+        values.sort_by(|a, b| a.total_cmp(b));
+        let n = values.len();
+        if n == 0 {
+            0.0
+        } else if n.is_multiple_of(2) {
+            (values[n / 2 - 1] + values[n / 2]) / 2.0
+        } else {
+            values[n / 2]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::GraphType;
+
+    fn tone(frequency: f64, sample_rate: f32, len: usize) -> ChartModel {
+        let data = (0..len)
+            .map(|i| {
+                let t = i as f64 / sample_rate as f64;
+                Point::new(t, (TAU * frequency * t).cos())
+            })
+            .collect();
+        ChartModel::new(data, GraphType::Line, sample_rate, "tone", None)
+    }
+
+    #[test]
+    fn resample_round_trip_keeps_tone_at_new_nyquist() {
+        let mut service = ChartProcessingService::new();
+        let original = tone(8.0, 64.0, 64);
+
+        let down = service.resample(&original, 16.0).unwrap();
+        assert_eq!(down.len(), 16);
+        for (i, p) in down.iter().enumerate() {
+            let expected = if i % 2 == 0 { 1.0 } else { -1.0 };
+            assert!((p.y - expected).abs() < 1e-9, "sample {}: {}", i, p.y);
+        }
+
+        let down = ChartModel::new(down, GraphType::Line, 16.0, "down", None);
+        let up = service.resample(&down, 64.0).unwrap();
+        assert_eq!(up.len(), original.data.len());
+        for (p, q) in up.iter().zip(&original.data) {
+            assert!((p.y - q.y).abs() < 1e-9, "{} != {}", p.y, q.y);
+        }
+    }
+
+    fn chirp(sample_rate: f32, len: usize) -> ChartModel {
+        let data = (0..len)
+            .map(|i| {
+                let t = i as f64 / sample_rate as f64;
+                Point::new(t, (TAU * (3.0 + 10.0 * t) * t).sin() + 0.5 * t - 0.2)
+            })
+            .collect();
+        ChartModel::new(data, GraphType::Line, sample_rate, "chirp", None)
+    }
+
+    fn assert_reconstructs(reconstructed: &[Point], original: &ChartModel) {
+        assert_eq!(reconstructed.len(), original.data.len());
+        for (p, q) in reconstructed.iter().zip(&original.data) {
+            assert!((p.x - q.x).abs() < 1e-9, "x {} != {}", p.x, q.x);
+            assert!((p.y - q.y).abs() < 1e-9, "y at {}: {} != {}", q.x, p.y, q.y);
+        }
+    }
+
+    #[test]
+    fn fft_inverse_round_trip() {
+        let mut service = ChartProcessingService::new();
+        let original = chirp(100.0, 137);
+
+        let spectrum = service.fft_complex(&original, None).unwrap();
+        let reconstructed = service.fft_inverse(&spectrum).unwrap();
+        assert_reconstructs(&reconstructed, &original);
+    }
+
+    #[test]
+    fn stft_inverse_round_trip() {
+        let mut service = ChartProcessingService::new();
+        let original = chirp(100.0, 250);
+
+        for (window_size, hop_size) in [(32, 8), (64, 16), (33, 11)] {
+            let stft = service
+                .stft_forward(&original, window_size, hop_size)
+                .unwrap();
+            let reconstructed = service.stft_inverse(&stft).unwrap();
+            assert_reconstructs(&reconstructed, &original);
+        }
+    }
+
+    #[test]
+    fn dwt_inverse_round_trip() {
+        let service = ChartProcessingService::new();
+        let original = chirp(100.0, 203);
+
+        let wavelets = [
+            Wavelet::Haar,
+            Wavelet::Daubechies(4),
+            Wavelet::Symlet(6),
+            Wavelet::Coiflet(2),
+        ];
+        let modes = [
+            ExtensionMode::Symmetric,
+            ExtensionMode::Reflect,
+            ExtensionMode::Periodic,
+            ExtensionMode::Constant,
+            ExtensionMode::Zero,
+        ];
+        for wavelet in wavelets {
+            for mode in modes {
+                let decomposition = service.dwt_forward(&original, wavelet, 3, mode).unwrap();
+                let reconstructed = service.dwt_inverse(&decomposition);
+                assert_reconstructs(&reconstructed, &original);
+            }
+        }
+    }
+
+    #[test]
+    fn block_average_keeps_dc() {
+        let mut service = ChartProcessingService::new();
+        let mut signal = tone(8.0, 64.0, 256);
+        signal.data.iter_mut().for_each(|p| p.y += 0.5);
+
+        let (average, _, blocks, left_out) = service
+            .block_average(
+                &signal,
+                Some(64),
+                0.0,
+                WindowType::Rectangular,
+                BlockAveraging::Linear,
+                BlockSpread::None,
+            )
+            .unwrap();
+        assert_eq!((blocks, left_out), (4, 0));
+        assert!((average[0].y - 0.5).abs() < 1e-9, "DC {}", average[0].y);
+        assert!((average[8].y - 1.0).abs() < 1e-9, "8 Hz {}", average[8].y);
     }
 }
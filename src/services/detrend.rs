@@ -0,0 +1,114 @@
+use std::{fmt, str::FromStr};
+
+use crate::services::least_squares;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetrendType {
+    Mean,
+    Linear,
+    Polynomial(u8),
+    Piecewise(u8),
+}
+
+impl DetrendType {
+    /// Remove trend
+    ///
+    /// Subtract the least squares fit of the selected trend model
+    /// from the given samples.
+    ///
+    /// ---
+    ///
+    /// * `samples`: The signal values to detrend.
+    pub fn apply(&self, samples: &[f64]) -> Vec<f64> {
+        match self {
+            DetrendType::Mean => polynomial_residual(samples, 0),
+            DetrendType::Linear => polynomial_residual(samples, 1),
+            DetrendType::Polynomial(order) => polynomial_residual(samples, *order as usize),
+            DetrendType::Piecewise(segments) => {
+                let segment_len = samples.len().div_ceil(*segments as usize).max(1);
+                samples
+                    .chunks(segment_len)
+                    .flat_map(|segment| polynomial_residual(segment, 1))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Fit a polynomial of the given order by least squares and return the residual.
+///
+/// The abscissa is mapped onto [-1, 1] to keep the normal equations well conditioned.
+fn polynomial_residual(samples: &[f64], order: usize) -> Vec<f64> {
+    let n = samples.len();
+    let order = order.min(n.saturating_sub(1));
+    let size = order + 1;
+    let abscissa = |i: usize| {
+        if n > 1 {
+            2.0 * i as f64 / (n - 1) as f64 - 1.0
+        } else {
+            0.0
+        }
+    };
+
+    // Normal equations augmented with the right-hand side
+    let mut system = vec![vec![0.0; size + 1]; size];
+    for (i, y) in samples.iter().enumerate() {
+        let x = abscissa(i);
+        let powers: Vec<f64> = (0..=2 * order).map(|p| x.powi(p as i32)).collect();
+        for (row, equation) in system.iter_mut().enumerate() {
+            for (col, coefficient) in equation.iter_mut().take(size).enumerate() {
+                *coefficient += powers[row + col];
+            }
+            equation[size] += powers[row] * y;
+        }
+    }
+
+    let coefficients = least_squares::solve(system);
+
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, y)| {
+            let x = abscissa(i);
+            let trend: f64 = coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |acc, coefficient| acc * x + coefficient);
+            y - trend
+        })
+        .collect()
+}
+
+impl FromStr for DetrendType {
+    type Err = strum::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let count = |prefix: &str, range: std::ops::RangeInclusive<u8>| {
+            s.strip_prefix(prefix)
+                .and_then(|count| count.parse::<u8>().ok())
+                .filter(|count| range.contains(count))
+        };
+        if s == "mean" {
+            Ok(DetrendType::Mean)
+        } else if s == "linear" {
+            Ok(DetrendType::Linear)
+        } else if let Some(order) = count("poly", 1..=10) {
+            Ok(DetrendType::Polynomial(order))
+        } else if let Some(segments) = count("seg", 1..=255) {
+            Ok(DetrendType::Piecewise(segments))
+        } else {
+            Err(strum::ParseError::VariantNotFound)
+        }
+    }
+}
+
+impl fmt::Display for DetrendType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DetrendType::Mean => write!(f, "mean"),
+            DetrendType::Linear => write!(f, "linear"),
+            DetrendType::Polynomial(order) => write!(f, "poly{}", order),
+            DetrendType::Piecewise(segments) => write!(f, "seg{}", segments),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use anyhow::Result;
+
+use crate::{
+    models::chart_view::chart::chart_model::ChartModel,
+    services::{
+        chart_processor::{ArithmeticOperation, ChartProcessingService},
+        handlers::ProcessingRequest,
+    },
+    shared::{commands::chart_view::ProcessingCommands, errors::commands::CommandError},
+    utils::{
+        command_args::{ChartOperand, required_arg},
+        expression::Expression,
+    },
+};
+
+/// `:add`, `:sub`, `:mul` and `:div` with a chart or a scalar.
+pub fn arithmetic(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let operation = match request.command {
+        ProcessingCommands::Add => ArithmeticOperation::Add,
+        ProcessingCommands::Subtract => ArithmeticOperation::Subtract,
+        ProcessingCommands::Multiply => ArithmeticOperation::Multiply,
+        _ => ArithmeticOperation::Divide,
+    };
+    let operand: ChartOperand = required_arg(&args, 1)?;
+    let current_chart = request.current()?;
+    let (data, operand_title) = match operand {
+        ChartOperand::Chart(index) => {
+            let other = request.chart(index)?;
+            let data = service.chart_arithmetic(&current_chart, &other, operation)?;
+            (data, other.metadata.title.clone())
+        }
+        ChartOperand::Scalar(value) => (
+            service.scalar_arithmetic(&current_chart, value, operation),
+            value.to_string(),
+        ),
+    };
+    let mut chart = ChartModel::new(
+        data,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &format!(
+            "{} {} {}",
+            current_chart.metadata.title, operation, operand_title
+        ),
+        Some(current_chart.metadata.transform),
+    );
+    chart.metadata.details = current_chart.metadata.details.clone();
+    // Products and quotients of two charts have a unit of their own
+    if matches!(operand, ChartOperand::Scalar(_))
+        || matches!(
+            operation,
+            ArithmeticOperation::Add | ArithmeticOperation::Subtract
+        )
+    {
+        chart.metadata.unit = current_chart.metadata.unit;
+    }
+    Ok(vec![chart])
+}
+
+/// `:expr <expression>`, chart evaluated from an expression.
+pub fn expression(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let source = request.cmd.trim_start()[args[0].len()..].trim();
+    if source.is_empty() {
+        return Err(CommandError::NotEnoughArguments.into());
+    }
+    let expression: Expression = source.parse()?;
+    let mut referenced = Vec::new();
+    for index in expression.chart_references() {
+        let chart = request.chart(index)?;
+        referenced.push((index, chart));
+    }
+    let grid = match referenced.first() {
+        Some((_, chart)) => chart.clone(),
+        None => request.current()?,
+    };
+    let charts: Vec<(usize, &ChartModel)> = referenced
+        .iter()
+        .map(|(index, chart)| (*index, chart))
+        .collect();
+    let mut chart = ChartModel::new(
+        service.evaluate_expression(&expression, &grid, &charts),
+        grid.metadata.chart_display_type,
+        grid.sample_rate,
+        source,
+        Some(grid.metadata.transform),
+    );
+    chart.metadata.unit = expression.unit(&|index| {
+        charts
+            .iter()
+            .find(|(reference, _)| *reference == index)
+            .and_then(|(_, chart)| chart.metadata.unit)
+    });
+    Ok(vec![chart])
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+
+use crate::{
+    models::chart_view::chart::{chart_model::ChartModel, chart_transform::ChartTransform},
+    services::{
+        chart_processor::{CepstrumType, ChartProcessingService, LifterType},
+        handlers::ProcessingRequest,
+    },
+    utils::command_args::{optional_arg, required_arg},
+};
+
+/// `:ceps [type]`, real or power cepstrum.
+pub fn cepstrum(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let cepstrum_type: CepstrumType = optional_arg(&args, 1, CepstrumType::Real)?;
+    let current_chart = request.current()?;
+    let cepstrum = service.cepstrum(&current_chart)?;
+    let transform = match cepstrum_type {
+        CepstrumType::Real => ChartTransform::RealCepstrum,
+        CepstrumType::Power => ChartTransform::PowerCepstrum,
+    };
+    let mut chart = ChartModel::new(
+        service.cepstrum_points(&cepstrum, cepstrum_type),
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(transform),
+    );
+    chart.cepstrum = Some(cepstrum);
+    // Kept for the liftered spectrum, a cepstrum itself has no signal unit
+    chart.metadata.unit = current_chart.metadata.unit;
+    Ok(vec![chart])
+}
+
+/// `:lifter <type> <quefrency>`, liftered amplitude spectrum.
+pub fn lifter(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let lifter: LifterType = required_arg(&args, 1)?;
+    let quefrency: f64 = required_arg(&args, 2)?;
+    let current_chart = request.current()?;
+    let mut chart = ChartModel::new(
+        service.lifter(&current_chart, lifter, quefrency)?,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::Liftered),
+    );
+    chart.metadata.unit = current_chart.metadata.unit;
+    chart.metadata.details = Some(format!("{} {} s", lifter, quefrency));
+    Ok(vec![chart])
+}
@@ -0,0 +1,116 @@
+use anyhow::Result;
+
+use crate::{
+    models::chart_view::chart::{chart_model::ChartModel, chart_transform::ChartTransform},
+    services::{
+        chart_processor::{ChartProcessingService, CorrelationScale, FrfEstimator},
+        handlers::ProcessingRequest,
+        window::WindowType,
+    },
+    shared::{
+        commands::chart_view::ProcessingCommands,
+        constants::chart_processing::DEFAULT_WELCH_OVERLAP,
+    },
+    utils::command_args::{ChartReference, optional_arg, required_arg},
+};
+
+/// `:acorr [scale]` and `:xcorr <chart> [scale]`, correlation with the peak lag.
+pub fn correlation(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let current_chart = request.current()?;
+    let (reference, scale_index) = if request.command == ProcessingCommands::CrossCorrelation {
+        let ChartReference(index) = required_arg(&args, 1)?;
+        let reference = request.chart(index)?;
+        (Some(reference), 2)
+    } else {
+        (None, 1)
+    };
+    let scale: CorrelationScale = optional_arg(&args, scale_index, CorrelationScale::Coefficient)?;
+    let (data, peak, transform, reference_title) = match &reference {
+        Some(reference) => {
+            let (data, peak) = service.cross_correlation(&current_chart, reference, scale)?;
+            let title = reference.metadata.title.clone();
+            (data, peak, ChartTransform::CrossCorrelation, Some(title))
+        }
+        None => {
+            let (data, peak) = service.autocorrelation(&current_chart, scale)?;
+            (data, peak, ChartTransform::Autocorrelation, None)
+        }
+    };
+    let mut chart = ChartModel::new(
+        data,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(transform),
+    );
+    let peak = match peak {
+        Some(peak) => format!("peak lag {:.6} s", peak),
+        None => String::from("no peak"),
+    };
+    chart.metadata.details = Some(match reference_title {
+        Some(title) => format!("vs {}, {}", title, peak),
+        None => peak,
+    });
+    Ok(vec![chart])
+}
+
+/// `:coh` and `:frf`, coherence or frequency response from an input chart.
+pub fn coherence(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let mut results = Vec::new();
+    let ChartReference(index) = required_arg(&args, 1)?;
+    let segment_len: usize = required_arg(&args, 2)?;
+    let (estimator, options) = if request.command == ProcessingCommands::FrequencyResponse {
+        (optional_arg(&args, 3, FrfEstimator::H1)?, 4)
+    } else {
+        (FrfEstimator::H1, 3)
+    };
+    let overlap: f64 = optional_arg(&args, options, DEFAULT_WELCH_OVERLAP)?;
+    let window: WindowType = optional_arg(&args, options + 1, WindowType::Hann)?;
+    let current_chart = request.current()?;
+    let input = request.chart(index)?;
+    let spectra = if request.command == ProcessingCommands::Coherence {
+        let coherence = service.coherence(&input, &current_chart, segment_len, overlap, window)?;
+        vec![(coherence, ChartTransform::Coherence, String::new())]
+    } else {
+        let (magnitude, phase) = service.frf(
+            &input,
+            &current_chart,
+            estimator,
+            segment_len,
+            overlap,
+            window,
+        )?;
+        let label = format!("{} ", estimator);
+        vec![
+            (magnitude, ChartTransform::Magnitude, label.clone()),
+            (phase, ChartTransform::Phase, label),
+        ]
+    };
+    for (data, transform, label) in spectra {
+        let mut chart = ChartModel::new(
+            data,
+            current_chart.metadata.chart_display_type,
+            current_chart.sample_rate,
+            &current_chart.metadata.title,
+            Some(transform),
+        );
+        chart.metadata.details = Some(format!("{}from {}", label, input.metadata.title));
+        // A response is in output units per input unit, coherence has no unit
+        if request.command == ProcessingCommands::FrequencyResponse
+            && let (Some(unit), Some(per_unit)) = (current_chart.metadata.unit, input.metadata.unit)
+        {
+            chart.metadata.unit = Some(unit);
+            chart.metadata.per_unit = Some(per_unit);
+        }
+        results.push(chart);
+    }
+    Ok(results)
+}
@@ -0,0 +1,108 @@
+pub mod arithmetic;
+pub mod cepstrum;
+pub mod correlation;
+pub mod pipeline;
+pub mod signal;
+pub mod spectrum;
+pub mod time_frequency;
+
+use anyhow::Result;
+
+use crate::{
+    models::chart_view::chart::{chart_model::ChartModel, shared_chart::SharedChart},
+    services::chart_processor::ChartProcessingService,
+    shared::{commands::chart_view::ProcessingCommands, errors::commands::CommandError},
+};
+
+/// Command handed to the processing worker with the charts it needs.
+pub struct ProcessingRequest {
+    pub command: ProcessingCommands,
+    pub cmd: String,
+    pub current: Option<SharedChart>,
+    pub charts: Vec<SharedChart>,
+    pub view: (f64, f64),
+}
+
+impl ProcessingRequest {
+    pub fn args(&self) -> Vec<&str> {
+        self.cmd.split_whitespace().collect()
+    }
+
+    /// Copy of the current chart, so the UI keeps rendering while the job runs.
+    pub fn current(&self) -> Result<ChartModel> {
+        self.current
+            .as_ref()
+            .map(SharedChart::snapshot)
+            .ok_or_else(|| CommandError::NoChart.into())
+    }
+
+    /// Copy of the opened chart at `index`.
+    pub fn chart(&self, index: usize) -> Result<ChartModel> {
+        self.charts
+            .get(index)
+            .map(SharedChart::snapshot)
+            .ok_or_else(|| CommandError::InvalidArguments(index.to_string()).into())
+    }
+}
+
+/// Run a processing command
+///
+/// Executed on the worker thread, routing the command to its handler.
+/// Returns the charts to add once the job finishes.
+///
+/// ---
+///
+/// * `request`: The command and the charts it works on.
+/// * `service`: The processing service of the worker.
+pub fn process(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    match request.command {
+        ProcessingCommands::FastFourierTransform => spectrum::fft(request, service),
+        ProcessingCommands::FftFilterLowPass
+        | ProcessingCommands::FftFilterHighPass
+        | ProcessingCommands::FftFilterBandPass
+        | ProcessingCommands::FftFilterBandStop => spectrum::fft_filter(request, service),
+        ProcessingCommands::InverseTransform => spectrum::inverse(request, service),
+        ProcessingCommands::PhaseSpectrum => spectrum::phase_spectrum(request, service),
+        ProcessingCommands::RealSpectrum | ProcessingCommands::ImaginarySpectrum => {
+            spectrum::complex_part(request, service)
+        }
+        ProcessingCommands::BodePlot => spectrum::bode(request, service),
+        ProcessingCommands::PowerSpectralDensity => spectrum::psd(request, service),
+        ProcessingCommands::OctaveBands => spectrum::octave_bands(request, service),
+        ProcessingCommands::BlockAverage => spectrum::block_average(request, service),
+        ProcessingCommands::FindPeaks => spectrum::peaks(request, service),
+        ProcessingCommands::ShortTimeFourierTransform => time_frequency::stft(request, service),
+        ProcessingCommands::DiscreteWaveletTransform => time_frequency::dwt(request, service),
+        ProcessingCommands::ContinuousWaveletTransform => time_frequency::cwt(request, service),
+        ProcessingCommands::HilbertTransform => time_frequency::hilbert(request, service),
+        ProcessingCommands::EnvelopeSpectrum => time_frequency::envelope_spectrum(request, service),
+        ProcessingCommands::Autocorrelation | ProcessingCommands::CrossCorrelation => {
+            correlation::correlation(request, service)
+        }
+        ProcessingCommands::Coherence | ProcessingCommands::FrequencyResponse => {
+            correlation::coherence(request, service)
+        }
+        ProcessingCommands::Cepstrum => cepstrum::cepstrum(request, service),
+        ProcessingCommands::Lifter => cepstrum::lifter(request, service),
+        ProcessingCommands::Integrate | ProcessingCommands::Differentiate => {
+            signal::calculus(request, service)
+        }
+        ProcessingCommands::Crop => signal::crop(request, service),
+        ProcessingCommands::Smooth => signal::smooth(request, service),
+        ProcessingCommands::Despike | ProcessingCommands::Declip => {
+            signal::repair(request, service)
+        }
+        ProcessingCommands::Detrend => signal::detrend(request, service),
+        ProcessingCommands::Resample | ProcessingCommands::Decimate => {
+            signal::resample(request, service)
+        }
+        ProcessingCommands::Add
+        | ProcessingCommands::Subtract
+        | ProcessingCommands::Multiply
+        | ProcessingCommands::Divide => arithmetic::arithmetic(request, service),
+        ProcessingCommands::Expression => arithmetic::expression(request, service),
+    }
+}
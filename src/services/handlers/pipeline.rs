@@ -0,0 +1,116 @@
+use std::{collections::HashMap, path::Path, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+use crate::{
+    clients::traits::file_read_only::FileReadOnly,
+    models::{
+        chart_view::{
+            chart::{chart_model::ChartModel, shared_chart::SharedChart},
+            pipeline::Pipeline,
+        },
+        files::file_types::FileType,
+    },
+    services::{
+        chart_processor::ChartProcessingService,
+        handlers::{ProcessingRequest, process},
+    },
+    shared::errors::{files::FileError, pipeline::PipelineError},
+};
+
+/// Run a pipeline
+///
+/// Executed on the worker thread. Every step runs on the first chart
+/// created by the previous step, charts of skipped steps are dropped
+/// except for the last step.
+///
+/// ---
+///
+/// * `pipeline`: The pipeline to run.
+/// * `source`: The chart the first step runs on.
+/// * `charts`: The opened charts, referenced by steps such as `:xcorr`.
+/// * `service`: The processing service of the worker.
+pub fn run_pipeline(
+    pipeline: &Pipeline,
+    source: ChartModel,
+    charts: &[SharedChart],
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let mut results = Vec::new();
+    let mut input = source;
+    for (index, step) in pipeline.steps.iter().enumerate() {
+        let request = ProcessingRequest {
+            command: step.command,
+            cmd: step.cmd.clone(),
+            view: (input.x_min, input.x_max),
+            current: Some(SharedChart::new(input)),
+            charts: charts.to_vec(),
+        };
+        let outputs = process(&request, service)
+            .map_err(|err| PipelineError::StepFailed(step.to_string(), err.to_string()))?;
+        let Some(first) = outputs.first() else {
+            return Err(PipelineError::NoOutput(step.to_string()).into());
+        };
+        input = first.clone();
+        if step.keep || index + 1 == pipeline.steps.len() {
+            results.extend(outputs);
+        }
+    }
+    Ok(results)
+}
+
+/// Run a pipeline on files
+///
+/// Executed on the worker thread. The first channel of every file is
+/// read and run through the pipeline, stopping at the first failing file.
+///
+/// ---
+///
+/// * `pipeline`: The pipeline to run.
+/// * `paths`: The signal files to read.
+/// * `parsers`: The file parsers by file type.
+/// * `charts`: The opened charts, referenced by steps such as `:xcorr`.
+/// * `service`: The processing service of the worker.
+pub fn run_batch<P: AsRef<Path>>(
+    pipeline: &Pipeline,
+    paths: &[P],
+    parsers: &HashMap<FileType, Box<dyn FileReadOnly>>,
+    charts: &[SharedChart],
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let mut results = Vec::new();
+    for path in paths {
+        let path = path.as_ref();
+        let outputs = read_chart_file(parsers, path)
+            .and_then(|source| run_pipeline(pipeline, source, charts, service))
+            .map_err(|err| {
+                PipelineError::BatchFailed(path.display().to_string(), err.to_string())
+            })?;
+        results.extend(outputs);
+    }
+    Ok(results)
+}
+
+/// Read chart
+///
+/// Parse the first channel of a signal file with the parser matching
+/// its extension
+///
+/// ---
+///
+/// * `parsers`: The file parsers by file type
+/// * `path`: The path to the file to read
+pub fn read_chart_file(
+    parsers: &HashMap<FileType, Box<dyn FileReadOnly>>,
+    path: &Path,
+) -> Result<ChartModel> {
+    let extension = if let Some(extension) = path.extension() {
+        extension.to_str().ok_or(FileError::ExtensionParseError)?
+    } else {
+        return Err(anyhow!(FileError::PathParseError));
+    };
+    let parser = parsers
+        .get(&FileType::from_str(extension)?)
+        .ok_or(FileError::UnsupportedType)?;
+    parser.parse_signal_file(path.to_str().ok_or(FileError::PathParseError)?, 0)
+}
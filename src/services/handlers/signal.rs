@@ -0,0 +1,233 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    models::chart_view::chart::{
+        chart_model::ChartModel, chart_transform::ChartTransform, wavelet::ExtensionMode,
+    },
+    services::{
+        chart_processor::{
+            CalculusMethod, ChartProcessingService, OutlierDetector, RepairMode, SmoothingType,
+        },
+        detrend::DetrendType,
+        handlers::ProcessingRequest,
+    },
+    shared::{
+        commands::chart_view::ProcessingCommands,
+        constants::chart_processing::{
+            DEFAULT_CLIPPING_RUN, DEFAULT_CLIPPING_TOLERANCE, DEFAULT_HAMPEL_WINDOW,
+            DEFAULT_INTEGRATION_CUTOFF, DEFAULT_OUTLIER_THRESHOLD,
+        },
+        errors::commands::CommandError,
+    },
+    utils::command_args::{optional_arg, required_arg},
+};
+
+/// `:integrate` and `:differentiate`, integration or differentiation converting the unit.
+pub fn calculus(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let passes: i32 = optional_arg(&args, 1, 1)?;
+    let method: CalculusMethod = optional_arg(&args, 2, CalculusMethod::Frequency)?;
+    let cutoff: f64 = optional_arg(&args, 3, DEFAULT_INTEGRATION_CUTOFF)?;
+    if passes < 1 {
+        return Err(CommandError::InvalidArguments(passes.to_string()).into());
+    }
+    let current_chart = request.current()?;
+    let (signed_passes, transform) = if request.command == ProcessingCommands::Integrate {
+        (-passes, ChartTransform::Integrated)
+    } else {
+        (passes, ChartTransform::Differentiated)
+    };
+    let mut data = service.calculus(&current_chart, signed_passes, method, cutoff)?;
+    let mut unit = current_chart.metadata.unit;
+    for _ in 0..passes {
+        let converted = unit.and_then(|unit| {
+            if request.command == ProcessingCommands::Integrate {
+                unit.integrated()
+            } else {
+                unit.differentiated()
+            }
+        });
+        if let Some((_, scale)) = converted {
+            data.iter_mut().for_each(|p| p.y *= scale);
+        }
+        unit = converted.map(|(unit, _)| unit);
+    }
+    let mut chart = ChartModel::new(
+        data,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(transform),
+    );
+    chart.metadata.unit = unit;
+    chart.metadata.details = Some(format!("{} x{}", method, passes));
+    Ok(vec![chart])
+}
+
+/// `:crop [start] [end]`, segment of the chart, the visible range by default.
+pub fn crop(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let current_chart = request.current()?;
+    let start: f64 = optional_arg(&args, 1, request.view.0)?;
+    let end: f64 = optional_arg(&args, 2, request.view.1)?;
+    let mut chart = ChartModel::new(
+        service.crop(&current_chart, start, end)?,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        None,
+    );
+    chart.header = current_chart.header.clone();
+    chart.metadata.unit = current_chart.metadata.unit;
+    chart.metadata.details = Some(format!("{:.3}-{:.3} s", start, end));
+    Ok(vec![chart])
+}
+
+/// `:smooth <type> <window> [order] [mode]`, smoothed chart over the original.
+pub fn smooth(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let current_chart = request.current()?;
+    let smoothing: SmoothingType = required_arg(&args, 1)?;
+    let window: usize = required_arg(&args, 2)?;
+    let (order, mode_index) = if smoothing == SmoothingType::SavitzkyGolay {
+        (required_arg(&args, 3)?, 4)
+    } else {
+        (0, 3)
+    };
+    let mode: ExtensionMode = optional_arg(&args, mode_index, ExtensionMode::default())?;
+    let mut chart = ChartModel::new(
+        service.smooth(&current_chart, smoothing, window, order, mode)?,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::Smoothed),
+    );
+    chart.overlay = Some(Arc::new(current_chart.data.clone()));
+    chart.metadata.unit = current_chart.metadata.unit;
+    chart.metadata.details = Some(match smoothing {
+        SmoothingType::SavitzkyGolay => format!("{} {}/{}", smoothing, window, order),
+        _ => format!("{} {}", smoothing, window),
+    });
+    Ok(vec![chart])
+}
+
+/// `:despike` and `:declip`, repaired outliers or clipped plateaus.
+pub fn repair(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let current_chart = request.current()?;
+    let (flagged, mode, transform, method) = if request.command == ProcessingCommands::Despike {
+        let detector: OutlierDetector = required_arg(&args, 1)?;
+        let threshold: f64 = optional_arg(&args, 2, DEFAULT_OUTLIER_THRESHOLD)?;
+        let mode: RepairMode = optional_arg(&args, 3, RepairMode::default())?;
+        let window: usize = optional_arg(&args, 4, DEFAULT_HAMPEL_WINDOW)?;
+        (
+            service.detect_outliers(&current_chart, detector, threshold, window)?,
+            mode,
+            ChartTransform::Despiked,
+            format!("{} {}", detector, threshold),
+        )
+    } else {
+        let tolerance: f64 = optional_arg(&args, 1, DEFAULT_CLIPPING_TOLERANCE)?;
+        let min_run: usize = optional_arg(&args, 2, DEFAULT_CLIPPING_RUN)?;
+        let mode: RepairMode = optional_arg(&args, 3, RepairMode::default())?;
+        (
+            service.detect_clipping(&current_chart, tolerance, min_run)?,
+            mode,
+            ChartTransform::Declipped,
+            format!("{}%", tolerance),
+        )
+    };
+    let affected = flagged.iter().filter(|&&flag| flag).count();
+    // Removing samples leaves gaps in x, which the spectral commands cannot handle
+    let transform = if mode == RepairMode::Remove {
+        ChartTransform::NonUniform
+    } else {
+        transform
+    };
+    let mut chart = ChartModel::new(
+        service.repair(&current_chart, &flagged, mode)?,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(transform),
+    );
+    chart.overlay = Some(Arc::new(current_chart.data.clone()));
+    chart.header = current_chart.header.clone();
+    chart.metadata.unit = current_chart.metadata.unit;
+    chart.metadata.details = Some(format!(
+        "{}, {} of {} samples, {}{}",
+        method,
+        affected,
+        flagged.len(),
+        mode,
+        if mode == RepairMode::Remove {
+            ", non-uniform sampling"
+        } else {
+            ""
+        }
+    ));
+    Ok(vec![chart])
+}
+
+/// `:detrend <type>`, chart with the trend removed.
+pub fn detrend(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let current_chart = request.current()?;
+    let detrend: DetrendType = required_arg(&args, 1)?;
+    let mut chart = ChartModel::new(
+        service.detrend(&current_chart, detrend)?,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::Detrended),
+    );
+    chart.metadata.unit = current_chart.metadata.unit;
+    chart.metadata.details = Some(detrend.to_string());
+    Ok(vec![chart])
+}
+
+/// `:resample <rate>` and `:decimate <factor>`, chart at a new sample rate.
+pub fn resample(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let current_chart = request.current()?;
+    let (data, sample_rate) = if request.command == ProcessingCommands::Resample {
+        let rate: f32 = required_arg(&args, 1)?;
+        (service.resample(&current_chart, rate)?, rate)
+    } else {
+        let factor: usize = required_arg(&args, 1)?;
+        (
+            service.decimate(&current_chart, factor)?,
+            current_chart.sample_rate / factor as f32,
+        )
+    };
+    let mut chart = ChartModel::new(
+        data,
+        current_chart.metadata.chart_display_type,
+        sample_rate,
+        &current_chart.metadata.title,
+        None,
+    );
+    chart.metadata.unit = current_chart.metadata.unit;
+    chart.metadata.details = Some(format!("{} Hz", sample_rate));
+    Ok(vec![chart])
+}
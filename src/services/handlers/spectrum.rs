@@ -0,0 +1,306 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use ratatui::widgets::GraphType;
+
+use crate::{
+    models::chart_view::chart::{
+        chart_model::ChartModel, chart_transform::ChartTransform, point::Point,
+    },
+    services::{
+        chart_processor::{
+            AngleUnit, BlockAveraging, BlockSpread, ChartProcessingService, FftFilterType,
+            OctaveFraction, PeakInterpolation, PhaseMode, SpectralAveraging,
+        },
+        detrend::DetrendType,
+        handlers::ProcessingRequest,
+        window::WindowType,
+    },
+    shared::{
+        commands::chart_view::ProcessingCommands,
+        constants::chart_processing::{
+            DEFAULT_OCTAVE_SEGMENT, DEFAULT_PHASE_MASK_THRESHOLD, DEFAULT_WELCH_OVERLAP,
+        },
+        errors::commands::CommandError,
+    },
+    utils::command_args::{optional_arg, required_arg},
+};
+
+/// `:fft [detrend]`, magnitude spectrum keeping the complex spectrum for the inverse.
+pub fn fft(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let current_chart = request.current()?;
+    let detrend: Option<DetrendType> = args.get(1).map(|_| required_arg(&args, 1)).transpose()?;
+    let spectrum = service.fft_complex(&current_chart, detrend)?;
+    let origin = current_chart.samples();
+    let mut chart = ChartModel::new(
+        service.spectrum_magnitude(&spectrum),
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::Fft),
+    );
+    chart.spectrum = Some(spectrum);
+    chart.metadata.unit = current_chart.metadata.unit;
+    chart.origin = Some(match detrend {
+        Some(detrend) => Arc::new(detrend.apply(&origin)),
+        None => origin,
+    });
+    chart.metadata.details = detrend.map(|detrend| format!("{} detrend", detrend));
+    Ok(vec![chart])
+}
+
+/// `:flp`, `:fhp`, `:fbp` and `:fbs` filters of an FFT spectrum.
+pub fn fft_filter(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let filter = match request.command {
+        ProcessingCommands::FftFilterLowPass => FftFilterType::LowPass(required_arg(&args, 1)?),
+        ProcessingCommands::FftFilterHighPass => FftFilterType::HighPass(required_arg(&args, 1)?),
+        ProcessingCommands::FftFilterBandPass => {
+            FftFilterType::BandPass(required_arg(&args, 1)?, required_arg(&args, 2)?)
+        }
+        _ => FftFilterType::BandStop(required_arg(&args, 1)?, required_arg(&args, 2)?),
+    };
+    let current_chart = request.current()?;
+    let mut chart = ChartModel::new(
+        service.apply_fft_filter(&current_chart, filter)?,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::Filtered),
+    );
+    chart.spectrum = current_chart
+        .spectrum
+        .as_ref()
+        .map(|spectrum| service.filter_spectrum(spectrum, filter));
+    chart.metadata.unit = current_chart.metadata.unit;
+    // The origin is not kept, the inverse of an edited spectrum
+    // is not expected to reproduce it
+    Ok(vec![chart])
+}
+
+/// `:inv`, inverse of an FFT, STFT or wavelet chart.
+pub fn inverse(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let current_chart = request.current()?;
+    let data = service.inverse(&current_chart)?;
+    let error = current_chart
+        .origin
+        .as_ref()
+        .map(|origin| service.reconstruction_error(&data, origin));
+    let sample_rate = match &current_chart.wavelet {
+        Some(coefficients) => coefficients.decomposition.sample_rate,
+        None => current_chart.sample_rate,
+    };
+    let mut chart = ChartModel::new(
+        data,
+        GraphType::Line,
+        sample_rate,
+        &current_chart.metadata.title,
+        None,
+    );
+    chart.metadata.unit = current_chart.metadata.unit;
+    chart.metadata.details = error.map(|error| format!("RMS error {:.3e}", error));
+    Ok(vec![chart])
+}
+
+/// `:phs [mode] [unit] [threshold]`, phase of the FFT spectrum.
+pub fn phase_spectrum(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let mode: PhaseMode = optional_arg(&args, 1, PhaseMode::Wrapped)?;
+    let unit: AngleUnit = optional_arg(&args, 2, AngleUnit::Radians)?;
+    let threshold: f64 = optional_arg(&args, 3, DEFAULT_PHASE_MASK_THRESHOLD)?;
+    let current_chart = request.current()?;
+    let chart = ChartModel::new(
+        service.phase_spectrum(&current_chart, mode, unit, threshold)?,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::Phase),
+    );
+    Ok(vec![chart])
+}
+
+/// `:re` and `:im`, real or imaginary part of the FFT spectrum.
+pub fn complex_part(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let current_chart = request.current()?;
+    let (data, transform) = if request.command == ProcessingCommands::RealSpectrum {
+        (service.real_spectrum(&current_chart)?, ChartTransform::Real)
+    } else {
+        (
+            service.imaginary_spectrum(&current_chart)?,
+            ChartTransform::Imaginary,
+        )
+    };
+    let mut chart = ChartModel::new(
+        data,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(transform),
+    );
+    chart.metadata.unit = current_chart.metadata.unit;
+    Ok(vec![chart])
+}
+
+/// `:bode [threshold]`, magnitude and phase charts of the FFT spectrum.
+pub fn bode(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let mut results = Vec::new();
+    let threshold: f64 = optional_arg(&args, 1, DEFAULT_PHASE_MASK_THRESHOLD)?;
+    let current_chart = request.current()?;
+    let (magnitude, phase) = service.bode(&current_chart, threshold)?;
+    for (data, transform) in [
+        (magnitude, ChartTransform::Magnitude),
+        (phase, ChartTransform::Phase),
+    ] {
+        let mut chart = ChartModel::new(
+            data,
+            current_chart.metadata.chart_display_type,
+            current_chart.sample_rate,
+            &current_chart.metadata.title,
+            Some(transform),
+        );
+        chart.metadata.unit = current_chart.metadata.unit;
+        results.push(chart);
+    }
+    Ok(results)
+}
+
+/// `:psd <segment> [overlap] [window] [averaging]`, Welch power spectral density.
+pub fn psd(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let segment_len: usize = required_arg(&args, 1)?;
+    let overlap: f64 = optional_arg(&args, 2, DEFAULT_WELCH_OVERLAP)?;
+    let window: WindowType = optional_arg(&args, 3, WindowType::Hann)?;
+    let averaging: SpectralAveraging = optional_arg(&args, 4, SpectralAveraging::Mean)?;
+    let current_chart = request.current()?;
+    let mut chart = ChartModel::new(
+        service.welch_psd(&current_chart, segment_len, overlap, window, averaging)?,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::Psd),
+    );
+    chart.metadata.unit = current_chart.metadata.unit;
+    Ok(vec![chart])
+}
+
+/// `:oct [fraction] [segment]`, octave or third-octave band levels.
+pub fn octave_bands(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let fraction: OctaveFraction = optional_arg(&args, 1, OctaveFraction::ThirdOctave)?;
+    let current_chart = request.current()?;
+    let segment_len: usize = optional_arg(
+        &args,
+        2,
+        current_chart.data.len().min(DEFAULT_OCTAVE_SEGMENT),
+    )?;
+    let octave = service.octave_bands(&current_chart, fraction, segment_len)?;
+    let mut chart = ChartModel::new(
+        octave
+            .bands
+            .iter()
+            .map(|band| Point::new(band.nominal, band.level))
+            .collect(),
+        GraphType::Bar,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::OctaveBands),
+    );
+    chart.metadata.unit = current_chart.metadata.unit;
+    chart.metadata.details = Some(format!("{}, total {:.1} dB", fraction, octave.total));
+    chart.octave = Some(octave);
+    Ok(vec![chart])
+}
+
+/// `:bavg [averaging] [spread] [block] [overlap] [window]`, averaged block spectra.
+pub fn block_average(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let averaging: BlockAveraging = optional_arg(&args, 1, BlockAveraging::Linear)?;
+    let spread: BlockSpread = optional_arg(&args, 2, BlockSpread::None)?;
+    let current_chart = request.current()?;
+    let block_len: Option<usize> = args.get(3).map(|_| required_arg(&args, 3)).transpose()?;
+    let overlap: f64 = optional_arg(&args, 4, 0.0)?;
+    let window: WindowType = optional_arg(&args, 5, WindowType::Hann)?;
+    let (average, band, blocks, leftover) = service.block_average(
+        &current_chart,
+        block_len,
+        overlap,
+        window,
+        averaging,
+        spread,
+    )?;
+    let mut chart = ChartModel::new(
+        average,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::BlockAverage),
+    );
+    chart.band = band;
+    chart.metadata.unit = current_chart.metadata.unit;
+    let block_len = block_len
+        .or(current_chart
+            .header
+            .as_ref()
+            .map(|header| header.sample_size as usize))
+        .unwrap_or_default();
+    let mut details = format!("{}, {} blocks of {}", averaging, blocks, block_len);
+    if leftover > 0 {
+        details.push_str(&format!(", {} samples left out", leftover));
+    }
+    chart.metadata.details = Some(details);
+    Ok(vec![chart])
+}
+
+/// `:peaks [prominence] [distance] [threshold] [interpolation]`, marks the
+/// peaks of the current spectrum instead of adding a chart.
+pub fn peaks(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let prominence: f64 = optional_arg(&args, 1, 0.0)?;
+    let distance: f64 = optional_arg(&args, 2, 0.0)?;
+    let threshold: f64 = optional_arg(&args, 3, 0.0)?;
+    let interpolation: PeakInterpolation = optional_arg(&args, 4, PeakInterpolation::Parabolic)?;
+    let Some(current) = &request.current else {
+        return Err(CommandError::NoChart.into());
+    };
+    let peaks = service.find_peaks(
+        &current.snapshot(),
+        prominence,
+        distance,
+        threshold,
+        interpolation,
+    )?;
+    current.write().peaks = peaks;
+    Ok(Vec::new())
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    models::chart_view::chart::{
+        chart_model::ChartModel,
+        chart_transform::ChartTransform,
+        wavelet::{ExtensionMode, Wavelet, WaveletBand, WaveletCoefficients},
+    },
+    services::{
+        chart_processor::{ChartProcessingService, ContinuousWavelet},
+        handlers::ProcessingRequest,
+    },
+    shared::constants::chart_processing::DEFAULT_CWT_SCALES,
+    utils::command_args::{optional_arg, required_arg},
+};
+
+/// `:sft <window> <hop>`, spectrogram keeping the STFT frames.
+pub fn stft(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let window_size: usize = required_arg(&args, 1)?;
+    let hop_size: usize = required_arg(&args, 2)?;
+    let current_chart = request.current()?;
+    let stft = service.stft_forward(&current_chart, window_size, hop_size)?;
+    let mut chart = ChartModel::from_heatmap(
+        service.spectrogram(&stft),
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::Stft),
+    );
+    chart.stft = Some(stft);
+    chart.origin = Some(current_chart.samples());
+    Ok(vec![chart])
+}
+
+/// `:dwt <wavelet> <levels> [mode]`, one chart per detail level and the approximation.
+pub fn dwt(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let mut results = Vec::new();
+    let wavelet: Wavelet = required_arg(&args, 1)?;
+    let levels: usize = required_arg(&args, 2)?;
+    let mode: ExtensionMode = optional_arg(&args, 3, ExtensionMode::default())?;
+    let current_chart = request.current()?;
+    let decomposition = Arc::new(service.dwt_forward(&current_chart, wavelet, levels, mode)?);
+    let origin = current_chart.samples();
+    let title = &current_chart.metadata.title;
+    let bands = (1..=levels)
+        .map(WaveletBand::Detail)
+        .chain([WaveletBand::Approximation]);
+    for band in bands {
+        let (data, level, label, transform) = match band {
+            WaveletBand::Detail(level) => (
+                decomposition.detail_points(level),
+                level,
+                "D",
+                ChartTransform::WaveletDetail,
+            ),
+            WaveletBand::Approximation => (
+                decomposition.approximation_points(),
+                levels,
+                "A",
+                ChartTransform::WaveletApproximation,
+            ),
+        };
+        let mut chart = ChartModel::new(
+            data,
+            current_chart.metadata.chart_display_type,
+            decomposition.level_sample_rate(level),
+            &format!("{} {} {}{}", title, wavelet, label, level),
+            Some(transform),
+        );
+        chart.wavelet = Some(WaveletCoefficients {
+            decomposition: decomposition.clone(),
+            band,
+        });
+        chart.origin = Some(origin.clone());
+        results.push(chart);
+    }
+    Ok(results)
+}
+
+/// `:cwt <wavelet> <low> <high> [scales]`, scalogram.
+pub fn cwt(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let wavelet: ContinuousWavelet = required_arg(&args, 1)?;
+    let low: f64 = required_arg(&args, 2)?;
+    let high: f64 = required_arg(&args, 3)?;
+    let scales: usize = optional_arg(&args, 4, DEFAULT_CWT_SCALES)?;
+    let current_chart = request.current()?;
+    let chart = ChartModel::from_heatmap(
+        service.cwt(&current_chart, wavelet, low, high, scales)?,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::Cwt),
+    );
+    Ok(vec![chart])
+}
+
+/// `:hlb`, envelope, instantaneous phase and frequency charts.
+pub fn hilbert(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let mut results = Vec::new();
+    let current_chart = request.current()?;
+    let (envelope, phase, frequency) = service.hilbert(&current_chart)?;
+    for (data, transform) in [
+        (envelope, ChartTransform::Envelope),
+        (phase, ChartTransform::InstantaneousPhase),
+        (frequency, ChartTransform::InstantaneousFrequency),
+    ] {
+        let mut chart = ChartModel::new(
+            data,
+            current_chart.metadata.chart_display_type,
+            current_chart.sample_rate,
+            &current_chart.metadata.title,
+            Some(transform),
+        );
+        if transform == ChartTransform::Envelope {
+            chart.metadata.unit = current_chart.metadata.unit;
+        }
+        results.push(chart);
+    }
+    Ok(results)
+}
+
+/// `:envspec <low> <high>`, spectrum of the band-passed envelope.
+pub fn envelope_spectrum(
+    request: &ProcessingRequest,
+    service: &mut ChartProcessingService,
+) -> Result<Vec<ChartModel>> {
+    let args = request.args();
+    let low: f64 = required_arg(&args, 1)?;
+    let high: f64 = required_arg(&args, 2)?;
+    let current_chart = request.current()?;
+    let mut chart = ChartModel::new(
+        service.envelope_spectrum(&current_chart, low, high)?,
+        current_chart.metadata.chart_display_type,
+        current_chart.sample_rate,
+        &current_chart.metadata.title,
+        Some(ChartTransform::EnvelopeSpectrum),
+    );
+    chart.metadata.unit = current_chart.metadata.unit;
+    chart.metadata.details = Some(format!("band {}-{} Hz", low, high));
+    Ok(vec![chart])
+}
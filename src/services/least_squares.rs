@@ -0,0 +1,39 @@
+/// Solve a linear system
+///
+/// Gaussian elimination with partial pivoting on the augmented matrix
+/// `[A | b]`. Unknowns of singular pivots are left at zero.
+///
+/// ---
+///
+/// * `system`: The rows of the augmented matrix, each `size + 1` long.
+pub fn solve(mut system: Vec<Vec<f64>>) -> Vec<f64> {
+    let size = system.len();
+    for pivot in 0..size {
+        let best = (pivot..size)
+            .max_by(|&a, &b| system[a][pivot].abs().total_cmp(&system[b][pivot].abs()))
+            .unwrap_or(pivot);
+        system.swap(pivot, best);
+        if system[pivot][pivot].abs() < f64::EPSILON {
+            continue;
+        }
+        let (upper, lower) = system.split_at_mut(pivot + 1);
+        let pivot_row = &upper[pivot];
+        for row in lower.iter_mut() {
+            let factor = row[pivot] / pivot_row[pivot];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row).skip(pivot) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    let mut solution = vec![0.0; size];
+    for row in (0..size).rev() {
+        if system[row][row].abs() < f64::EPSILON {
+            continue;
+        }
+        let known: f64 = (row + 1..size)
+            .map(|col| system[row][col] * solution[col])
+            .sum();
+        solution[row] = (system[row][size] - known) / system[row][row];
+    }
+    solution
+}
@@ -1 +1,6 @@
 pub mod chart_processor;
+pub mod detrend;
+pub mod handlers;
+pub mod least_squares;
+pub mod window;
+pub mod worker;
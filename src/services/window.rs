@@ -0,0 +1,57 @@
+use std::f64::consts::TAU;
+
+use strum_macros::EnumString;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+pub enum WindowType {
+    #[strum(serialize = "rect")]
+    Rectangular,
+    #[strum(serialize = "hann")]
+    Hann,
+    #[strum(serialize = "hamming")]
+    Hamming,
+    #[strum(serialize = "blackman")]
+    Blackman,
+    #[strum(serialize = "flattop")]
+    FlatTop,
+}
+
+impl WindowType {
+    /// Window coefficients
+    ///
+    /// Generate the periodic (DFT-even) window of the given length,
+    /// suitable for spectral analysis of overlapping segments.
+    ///
+    /// ---
+    ///
+    /// * `len`: The number of coefficients to generate.
+    pub fn coefficients(&self, len: usize) -> Vec<f64> {
+        let cosine_sum = |a: &[f64]| -> Vec<f64> {
+            (0..len)
+                .map(|i| {
+                    let phase = TAU * i as f64 / len as f64;
+                    a.iter()
+                        .enumerate()
+                        .map(|(k, coef)| {
+                            let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+                            sign * coef * (phase * k as f64).cos()
+                        })
+                        .sum()
+                })
+                .collect()
+        };
+        match self {
+            WindowType::Rectangular => vec![1.0; len],
+            WindowType::Hann => cosine_sum(&[0.5, 0.5]),
+            WindowType::Hamming => cosine_sum(&[0.54, 0.46]),
+            WindowType::Blackman => cosine_sum(&[0.42, 0.5, 0.08]),
+            WindowType::FlatTop => cosine_sum(&[
+                0.215_578_95,
+                0.416_631_58,
+                0.277_263_158,
+                0.083_578_947,
+                0.006_947_368,
+            ]),
+        }
+    }
+}
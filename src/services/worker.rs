@@ -0,0 +1,214 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender, TryRecvError},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Result, anyhow};
+
+use crate::{
+    models::chart_view::chart::chart_model::ChartModel,
+    services::chart_processor::ChartProcessingService,
+    shared::errors::chart_processing::ChartProcessingError,
+};
+
+/// Processing job executed on the worker thread, producing the new charts.
+pub type Job = Box<dyn FnOnce(&mut ChartProcessingService) -> Result<Vec<ChartModel>> + Send>;
+
+type JobResult = (u64, Result<Vec<ChartModel>>);
+
+/// Progress of a running job, shared between the worker and the UI.
+#[derive(Default)]
+pub struct JobProgress {
+    done: AtomicUsize,
+    total: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl JobProgress {
+    /// Report progress
+    ///
+    /// Store how many of the job steps are done and check for cancellation,
+    /// so long loops can stop at the next step with `?`.
+    ///
+    /// ---
+    ///
+    /// * `done`: The number of finished steps.
+    /// * `total`: The total number of steps.
+    pub fn report(&self, done: usize, total: usize) -> Result<()> {
+        self.done.store(done, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+        self.check()
+    }
+
+    /// Start counting `total` steps from zero, see `advance`.
+    pub fn begin(&self, total: usize) {
+        self.done.store(0, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    /// Count one more finished step and check for cancellation.
+    pub fn advance(&self) -> Result<()> {
+        self.done.fetch_add(1, Ordering::Relaxed);
+        self.check()
+    }
+
+    /// Stop with `Cancelled` once the job is cancelled.
+    pub fn check(&self) -> Result<()> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Err(anyhow!(ChartProcessingError::Cancelled));
+        }
+        Ok(())
+    }
+
+    /// Finished part of the job between 0 and 1, 0 until a step is reported.
+    pub fn fraction(&self) -> f64 {
+        let total = self.total.load(Ordering::Relaxed);
+        if total == 0 {
+            return 0.0;
+        }
+        (self.done.load(Ordering::Relaxed) as f64 / total as f64).clamp(0.0, 1.0)
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Job currently tracked by the UI.
+pub struct RunningJob {
+    pub name: String,
+    pub progress: Arc<JobProgress>,
+    pub started: Instant,
+    id: u64,
+}
+
+impl RunningJob {
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
+
+/// Background thread running processing jobs one after another.
+///
+/// The worker owns its own `ChartProcessingService`, so the UI thread
+/// only sends jobs and polls for finished charts.
+pub struct ProcessingWorker {
+    jobs: Sender<(u64, Job, Arc<JobProgress>)>,
+    results: Receiver<JobResult>,
+    running: Option<RunningJob>,
+    next_id: u64,
+}
+
+impl Default for ProcessingWorker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProcessingWorker {
+    pub fn new() -> Self {
+        let (jobs, job_receiver) = mpsc::channel::<(u64, Job, Arc<JobProgress>)>();
+        let (result_sender, results) = mpsc::channel::<JobResult>();
+        thread::spawn(move || {
+            let mut service = ChartProcessingService::new();
+            for (id, job, progress) in job_receiver {
+                service.set_progress(progress);
+                let result = panic::catch_unwind(AssertUnwindSafe(|| job(&mut service)))
+                    .unwrap_or_else(|_| Err(anyhow!(ChartProcessingError::JobPanicked)));
+                if result_sender.send((id, result)).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            jobs,
+            results,
+            running: None,
+            next_id: 0,
+        }
+    }
+
+    /// Start a job
+    ///
+    /// Send the job to the worker thread. Only one job is tracked at a time.
+    ///
+    /// ---
+    ///
+    /// * `name`: The name shown next to the progress indicator.
+    /// * `job`: The job to run.
+    pub fn spawn(&mut self, name: &str, job: Job) -> Result<()> {
+        if let Some(running) = &self.running {
+            if running.progress.is_cancelled() {
+                return Err(anyhow!(ChartProcessingError::JobCancelling));
+            }
+            return Err(anyhow!(ChartProcessingError::JobRunning));
+        }
+        let progress = Arc::new(JobProgress::default());
+        self.next_id += 1;
+        self.jobs
+            .send((self.next_id, job, progress.clone()))
+            .map_err(|_| anyhow!(ChartProcessingError::JobPanicked))?;
+        self.running = Some(RunningJob {
+            name: String::from(name),
+            progress,
+            started: Instant::now(),
+            id: self.next_id,
+        });
+        Ok(())
+    }
+
+    /// Cancel the running job
+    ///
+    /// The job stops at its next progress report. It stays tracked as
+    /// cancelling until the worker returns it, so no other job starts on
+    /// the busy thread in the meantime, and its result is then dropped.
+    /// Returns `false` if no job was running or it was already cancelled.
+    pub fn cancel(&mut self) -> bool {
+        match &self.running {
+            Some(job) if !job.progress.is_cancelled() => {
+                job.progress.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Collect the result of the running job if it has finished.
+    ///
+    /// Results of cancelled jobs are dropped.
+    pub fn poll(&mut self) -> Option<Result<Vec<ChartModel>>> {
+        loop {
+            match self.results.try_recv() {
+                Ok((id, result)) => {
+                    let Some(job) = self.running.take_if(|job| job.id == id) else {
+                        continue;
+                    };
+                    if !job.progress.is_cancelled() {
+                        return Some(result);
+                    }
+                }
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => {
+                    return self
+                        .running
+                        .take()
+                        .map(|_| Err(anyhow!(ChartProcessingError::JobPanicked)));
+                }
+            }
+        }
+    }
+
+    pub fn status(&self) -> Option<&RunningJob> {
+        self.running.as_ref()
+    }
+}
@@ -1,7 +1,31 @@
+use std::str::FromStr;
+
+use strum::ParseError;
 use strum_macros::{AsRefStr, EnumString};
 
-#[derive(Debug, EnumString, AsRefStr, PartialEq, Eq)]
+/// Command of the chart view
+///
+/// View commands only change the view or the current chart in place and run
+/// immediately on the UI thread, processing commands run on the processing
+/// worker.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ChartViewCommands {
+    View(ViewCommands),
+    Processing(ProcessingCommands),
+}
+
+impl FromStr for ChartViewCommands {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ViewCommands::from_str(s)
+            .map(ChartViewCommands::View)
+            .or_else(|_| ProcessingCommands::from_str(s).map(ChartViewCommands::Processing))
+    }
+}
+
+#[derive(Debug, EnumString, AsRefStr, PartialEq, Eq, Clone, Copy)]
+pub enum ViewCommands {
     #[strum(serialize = ":zi")]
     ZoomIn,
     #[strum(serialize = ":zo")]
@@ -10,13 +34,30 @@ pub enum ChartViewCommands {
     MoveLeft,
     #[strum(serialize = ":mr")]
     MoveRight,
+    #[strum(serialize = ":clrpeaks")]
+    ClearPeaks,
+    #[strum(serialize = ":stats")]
+    Statistics,
+    #[strum(serialize = ":unit")]
+    Unit,
+    #[strum(serialize = ":export")]
+    Export,
+    #[strum(serialize = ":pipe")]
+    Pipeline,
+    #[strum(serialize = ":cmap")]
+    Colormap,
+    #[strum(serialize = ":drange")]
+    DynamicRange,
+}
 
+#[derive(Debug, EnumString, AsRefStr, PartialEq, Eq, Clone, Copy)]
+pub enum ProcessingCommands {
     #[strum(serialize = ":fft")]
     FastFourierTransform,
     #[strum(serialize = ":sft")]
     ShortTimeFourierTransform,
-    #[strum(serialize = ":hwt")]
-    HaarWaveletTransform,
+    #[strum(serialize = ":dwt")]
+    DiscreteWaveletTransform,
     #[strum(serialize = ":flp")]
     FftFilterLowPass,
     #[strum(serialize = ":fhp")]
@@ -25,4 +66,75 @@ pub enum ChartViewCommands {
     FftFilterBandPass,
     #[strum(serialize = ":fbs")]
     FftFilterBandStop,
+    #[strum(serialize = ":phs")]
+    PhaseSpectrum,
+    #[strum(serialize = ":re")]
+    RealSpectrum,
+    #[strum(serialize = ":im")]
+    ImaginarySpectrum,
+    #[strum(serialize = ":bode")]
+    BodePlot,
+    #[strum(serialize = ":cwt")]
+    ContinuousWaveletTransform,
+    #[strum(serialize = ":hlb")]
+    HilbertTransform,
+    #[strum(serialize = ":envspec")]
+    EnvelopeSpectrum,
+    #[strum(serialize = ":acorr")]
+    Autocorrelation,
+    #[strum(serialize = ":xcorr")]
+    CrossCorrelation,
+    #[strum(serialize = ":coh")]
+    Coherence,
+    #[strum(serialize = ":frf")]
+    FrequencyResponse,
+    #[strum(serialize = ":peaks")]
+    FindPeaks,
+    #[strum(serialize = ":ceps")]
+    Cepstrum,
+    #[strum(serialize = ":lifter")]
+    Lifter,
+    #[strum(serialize = ":integrate")]
+    Integrate,
+    #[strum(serialize = ":differentiate")]
+    Differentiate,
+    #[strum(serialize = ":add")]
+    Add,
+    #[strum(serialize = ":sub")]
+    Subtract,
+    #[strum(serialize = ":mul")]
+    Multiply,
+    #[strum(serialize = ":div")]
+    Divide,
+    #[strum(serialize = ":expr")]
+    Expression,
+    #[strum(serialize = ":smooth")]
+    Smooth,
+    #[strum(serialize = ":despike")]
+    Despike,
+    #[strum(serialize = ":declip")]
+    Declip,
+    #[strum(serialize = ":crop")]
+    Crop,
+    #[strum(serialize = ":bavg")]
+    BlockAverage,
+    #[strum(serialize = ":oct")]
+    OctaveBands,
+    #[strum(serialize = ":detrend")]
+    Detrend,
+    #[strum(serialize = ":resample")]
+    Resample,
+    #[strum(serialize = ":decimate")]
+    Decimate,
+    #[strum(serialize = ":inv")]
+    InverseTransform,
+    #[strum(serialize = ":psd")]
+    PowerSpectralDensity,
+}
+
+impl ProcessingCommands {
+    /// Whether the command adds new charts, `:peaks` only marks the current one.
+    pub fn creates_charts(&self) -> bool {
+        *self != ProcessingCommands::FindPeaks
+    }
 }
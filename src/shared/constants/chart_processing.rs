@@ -0,0 +1,16 @@
+pub const DEFAULT_PHASE_MASK_THRESHOLD: f64 = 0.1;
+
+pub const DEFAULT_WELCH_OVERLAP: f64 = 50.0;
+pub const DEFAULT_CWT_SCALES: usize = 64;
+
+pub const DEFAULT_OCTAVE_SEGMENT: usize = 16384;
+
+pub const DEFAULT_INTEGRATION_CUTOFF: f64 = 2.0;
+
+pub const MAX_SPECTRAL_PEAKS: usize = 20;
+pub const HARMONIC_TOLERANCE: f64 = 0.02;
+
+pub const DEFAULT_OUTLIER_THRESHOLD: f64 = 3.0;
+pub const DEFAULT_HAMPEL_WINDOW: usize = 7;
+pub const DEFAULT_CLIPPING_TOLERANCE: f64 = 0.1;
+pub const DEFAULT_CLIPPING_RUN: usize = 3;
@@ -3,3 +3,21 @@ pub const DEFAULT_CHART_ZOOM_MULTIPLIER: f64 = 1.0;
 
 pub const ZOOM_IN_COEFFICIENT: f64 = 1.2;
 pub const ZOOM_OUT_COEFFICIENT: f64 = 0.8;
+
+pub const DEFAULT_DYNAMIC_RANGE: f64 = 80.0;
+pub const HEATMAP_LEGEND_WIDTH: u16 = 12;
+pub const HEATMAP_CELL_SYMBOL: &str = "▀";
+pub const HEATMAP_BOUNDARY_SYMBOL: &str = "•";
+
+pub const SIDE_PANEL_WIDTH: u16 = 36;
+pub const PEAK_PANEL_TITLE: &str = "Peaks";
+pub const STATISTICS_PANEL_TITLE: &str = "Statistics";
+pub const STATISTICS_PANEL_HEIGHT: u16 = 13;
+
+pub const OCTAVE_PANEL_TITLE: &str = "Octave Bands";
+pub const OCTAVE_TOTAL_LABEL: &str = "Σ";
+pub const OCTAVE_BAR_GAP: u16 = 1;
+
+pub const JOB_PROGRESS_HEIGHT: u16 = 1;
+pub const JOB_CANCEL_HINT: &str = "Esc to cancel";
+pub const JOB_CANCELLING_HINT: &str = "cancelling";
@@ -1,3 +1,5 @@
 pub const DEFAULT_COMMAND_PREFIX: &str = ":";
 pub const DEFAULT_CURSOR: &str = "|";
 pub const BUFFER_SIZE: usize = 100;
+pub const CHART_REFERENCE_PREFIX: char = '#';
+pub const EXPRESSION_CHART_PREFIX: char = 'c';
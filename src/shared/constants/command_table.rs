@@ -1,6 +1,6 @@
 pub const BLOCK_TITLE: &str = "Command Table";
 pub const COMMAND_LIST_TABLE_HEADERS: (&str, &str, &str) = ("Command", "Arguments", "Description");
-pub const COMMAND_LIST: [(&str, &str, &str); 18] = [
+pub const COMMAND_LIST: [(&str, &str, &str); 57] = [
     //GeneralCommands
     ("ce", "-", "Show or hide chart explorer"),
     ("a", "-", "Show application about information"),
@@ -11,13 +11,167 @@ pub const COMMAND_LIST: [(&str, &str, &str); 18] = [
     ("zo", "Scale coefficient (Float)", "Shrink chart"),
     ("ml", "Number of steps (Int)", "Move chart left"),
     ("mr", "Number of steps (Int)", "Move chart right"),
-    ("fft", "-", "Perform Fast Fourier Transform"),
+    (
+        "fft",
+        "mean/linear/polyN/segN (String)",
+        "Perform Fast Fourier Transform",
+    ),
     (
         "sft",
         "window size (Int), hop size (Int)",
-        "Perform Short-Time Fourier Transforms",
+        "Show Short-Time Fourier Transform spectrogram",
+    ),
+    (
+        "cmap",
+        "viridis/magma/jet/gray (String)",
+        "Set spectrogram colormap",
+    ),
+    (
+        "drange",
+        "range in dB (Float)",
+        "Set spectrogram dynamic range",
+    ),
+    (
+        "dwt",
+        "wavelet (haar/db2-10/sym2-10/coif1-3), levels (Int), sym/reflect/per/const/zero (String)",
+        "Perform multi-level Discrete Wavelet Transform",
+    ),
+    (
+        "cwt",
+        "morlet/mexh/paul (String), low freq (Float), high freq (Float), scales (Int)",
+        "Show Continuous Wavelet Transform scalogram",
+    ),
+    (
+        "hlb",
+        "-",
+        "Show envelope, instantaneous phase and frequency (Hilbert transform)",
+    ),
+    (
+        "envspec",
+        "low band edge (Float), high band edge (Float)",
+        "Show envelope spectrum of the band-passed signal",
+    ),
+    (
+        "acorr",
+        "none/biased/unbiased/coeff (String)",
+        "Show autocorrelation of chart",
+    ),
+    (
+        "xcorr",
+        "#chart index (String), none/biased/unbiased/coeff (String)",
+        "Show cross-correlation with another chart",
+    ),
+    (
+        "coh",
+        "#input chart index (String), segment length (Int), overlap % (Float), window (String)",
+        "Show coherence between input chart and current chart",
+    ),
+    (
+        "frf",
+        "#input chart index (String), segment length (Int), h1/h2 (String), overlap % (Float), window (String)",
+        "Show frequency response from input chart to current chart",
+    ),
+    (
+        "peaks",
+        "prominence (Float), distance Hz (Float), threshold (Float), parabolic/gaussian (String)",
+        "Find and mark spectral peaks and harmonics",
+    ),
+    ("clrpeaks", "-", "Remove spectral peak marks"),
+    (
+        "stats",
+        "view/all/off (String)",
+        "Show statistics of the visible range or of the whole chart",
+    ),
+    (
+        "ceps",
+        "real/power (String)",
+        "Show cepstrum over quefrency in seconds",
+    ),
+    (
+        "lifter",
+        "low/high/comb (String), quefrency s (Float)",
+        "Lifter the cepstrum and rebuild the spectrum",
+    ),
+    (
+        "integrate",
+        "passes (Int), freq/time (String), cutoff Hz (Float)",
+        "Integrate chart, e.g. acceleration to velocity, units convert once set with :unit",
+    ),
+    (
+        "differentiate",
+        "passes (Int), freq/time (String)",
+        "Differentiate chart, e.g. velocity to acceleration, units convert once set with :unit",
+    ),
+    (
+        "unit",
+        "g, m/s2, m/s, mm/s, m, mm, um (String)",
+        "Set measurement unit of chart, Vibric files carry none",
+    ),
+    (
+        "add",
+        "#chart index or value (String)",
+        "Add a chart or a scalar to current chart",
+    ),
+    (
+        "sub",
+        "#chart index or value (String)",
+        "Subtract a chart or a scalar from current chart",
+    ),
+    (
+        "mul",
+        "#chart index or value (String)",
+        "Multiply current chart by a chart or a scalar",
+    ),
+    (
+        "div",
+        "#chart index or value (String)",
+        "Divide current chart by a chart or a scalar",
+    ),
+    (
+        "expr",
+        "expression of #N or cN, t, pi, e and math functions (String)",
+        "Create a chart from a formula, e.g. abs(c0 - 0.5*#2)",
+    ),
+    (
+        "crop",
+        "start s (Float), end s (Float)",
+        "Create a chart from the visible range or the given times",
+    ),
+    (
+        "smooth",
+        "ma/ema/median/sg (String), window (Int), order for sg (Int), sym/reflect/per/const/zero (String)",
+        "Smooth chart and overlay it on the original",
+    ),
+    (
+        "despike",
+        "hampel/zscore/mad (String), threshold (Float), interp/hold/remove (String), hampel window (Int)",
+        "Detect and repair spikes and dropouts, remove leaves a non-uniform chart",
+    ),
+    (
+        "declip",
+        "tolerance % (Float), min plateau length (Int), interp/hold/remove (String)",
+        "Detect and repair clipped plateaus, remove leaves a non-uniform chart",
+    ),
+    (
+        "detrend",
+        "mean/linear/polyN/segN (String)",
+        "Remove offset, drift or baseline from chart",
+    ),
+    (
+        "resample",
+        "sample rate (Float)",
+        "Resample chart to a new sample rate",
+    ),
+    (
+        "decimate",
+        "factor (Int)",
+        "Low-pass filter and downsample chart",
+    ),
+    (
+        "inv",
+        "-",
+        "Rebuild the time signal from an FFT, STFT or DWT chart",
     ),
-    ("hwt", "-", "Perform Haar Wavelet Transform"),
     ("flp", "filter value (Float)", "Apply LowPass Filter"),
     ("fhp", "filter value (Float)", "Apply HighPass Filter"),
     (
@@ -30,6 +184,43 @@ pub const COMMAND_LIST: [(&str, &str, &str); 18] = [
         "low filter value (Float) high filter value (Float)",
         "Apply BandStop Filter",
     ),
+    (
+        "phs",
+        "wrap/unwrap (String), rad/deg (String), mask threshold (Float)",
+        "Show phase of the FFT spectrum",
+    ),
+    ("re", "-", "Show real part of the FFT spectrum"),
+    ("im", "-", "Show imaginary part of the FFT spectrum"),
+    (
+        "bode",
+        "mask threshold (Float)",
+        "Show magnitude (dB) and phase (deg) of the FFT spectrum",
+    ),
+    (
+        "psd",
+        "segment length (Int), overlap % (Float), window (String), mean/median (String)",
+        "Estimate Welch power spectral density in unit²/Hz of the :unit set",
+    ),
+    (
+        "bavg",
+        "linear/exp/peak (String), none/minmax (String), block length (Int), overlap % (Float), window (String)",
+        "Average amplitude spectra of acquisition blocks, linear and exp average power (RMS)",
+    ),
+    (
+        "oct",
+        "1/1 or 1/3 (String), segment length (Int)",
+        "Show octave or third-octave band levels",
+    ),
+    (
+        "export",
+        "File path (String)",
+        "Export chart points or band levels to a CSV file",
+    ),
+    (
+        "pipe",
+        "define name steps / run name / batch name files / save path [force] / load path (String)",
+        "Define, run and store pipelines of ;-separated commands, -step skips its charts",
+    ),
     //ChartExplorerCommands
     ("of", "File path (String)", "Open new signal file"),
     ("cwv", "-", "Close current chart view"),
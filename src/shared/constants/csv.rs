@@ -0,0 +1,3 @@
+pub const CSV_POINTS_HEADER: &str = "x,y";
+pub const CSV_OCTAVE_HEADER: &str = "nominal_hz,center_hz,lower_hz,upper_hz,level_db";
+pub const CSV_OCTAVE_TOTAL: &str = "total";
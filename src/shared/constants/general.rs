@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use ratatui::style::Color;
 
 pub const DEFAULT_COLOR: Color = Color::LightYellow;
@@ -10,3 +12,5 @@ pub const INPUT_KEY_2: char = 'I';
 
 pub const EXPLORER_KEY_1: char = 'e';
 pub const EXPLORER_KEY_2: char = 'E';
+
+pub const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(100);
@@ -1,8 +1,12 @@
 pub mod about;
 pub mod chart;
 pub mod chart_explorer;
+pub mod chart_processing;
 pub mod chart_view;
 pub mod command;
 pub mod command_table;
+pub mod csv;
 pub mod general;
+pub mod pipeline;
 pub mod vibric;
+pub mod wavelet;
@@ -0,0 +1,4 @@
+pub const PIPELINE_STEP_SEPARATOR: char = ';';
+pub const PIPELINE_NAME_SEPARATOR: char = '=';
+pub const PIPELINE_SKIP_PREFIX: char = '-';
+pub const PIPELINE_COMMENT_PREFIX: char = '#';
@@ -0,0 +1,317 @@
+// Decomposition low-pass filters of the orthogonal wavelet families.
+pub const HAAR: [f64; 2] = [std::f64::consts::FRAC_1_SQRT_2, std::f64::consts::FRAC_1_SQRT_2];
+
+pub const DB2: [f64; 4] = [
+    -0.12940952255126045,
+    0.22414386804201333,
+    0.836516303737808,
+    0.4829629131445342,
+];
+
+pub const DB3: [f64; 6] = [
+    0.03522629188570956,
+    -0.08544127388202663,
+    -0.13501102001025464,
+    0.4598775021184915,
+    0.8068915093110927,
+    0.3326705529500827,
+];
+
+pub const DB4: [f64; 8] = [
+    -0.010597401785069039,
+    0.0328830116668852,
+    0.030841381835560774,
+    -0.18703481171909309,
+    -0.027983769416859854,
+    0.6308807679298589,
+    0.7148465705529159,
+    0.23037781330889653,
+];
+
+pub const DB5: [f64; 10] = [
+    0.0033357252854737734,
+    -0.012580751999082,
+    -0.006241490212798287,
+    0.07757149384004573,
+    -0.03224486958463838,
+    -0.24229488706638203,
+    0.13842814590132044,
+    0.7243085284377732,
+    0.6038292697971898,
+    0.16010239797419296,
+];
+
+pub const DB6: [f64; 12] = [
+    -0.0010773010853084811,
+    0.004777257510945516,
+    0.0005538422011615079,
+    -0.03158203931748607,
+    0.02752286553030573,
+    0.09750160558732295,
+    -0.12976686756726144,
+    -0.22626469396544033,
+    0.3152503517091978,
+    0.7511339080210954,
+    0.4946238903984531,
+    0.1115407433501095,
+];
+
+pub const DB7: [f64; 14] = [
+    0.00035371379997452024,
+    -0.001801640704047492,
+    0.0004295779729213707,
+    0.012550998556099832,
+    -0.016574541630666847,
+    -0.03802993693501452,
+    0.08061260915108326,
+    0.0713092192668301,
+    -0.22403618499387487,
+    -0.14390600392856545,
+    0.4697822874051936,
+    0.729132090846235,
+    0.39653931948191723,
+    0.07785205408500917,
+];
+
+pub const DB8: [f64; 16] = [
+    -0.0001174767841247694,
+    0.0006754494064505689,
+    -0.0003917403733769471,
+    -0.004870352993451571,
+    0.008746094047405784,
+    0.013981027917398251,
+    -0.04408825393079478,
+    -0.01736930100180726,
+    0.1287474266204779,
+    0.00047248457391391727,
+    -0.28401554296154746,
+    -0.015829105256348976,
+    0.585354683654207,
+    0.6756307362972896,
+    0.3128715909142999,
+    0.05441584224310401,
+];
+
+pub const DB9: [f64; 18] = [
+    3.934732031627146e-5,
+    -0.00025196318894270936,
+    0.00023038576352319554,
+    0.0018476468830562203,
+    -0.0042815036824634155,
+    -0.004723204757751389,
+    0.022361662123679068,
+    0.0002509471148313952,
+    -0.06763282906132985,
+    0.030725681479333636,
+    0.14854074933810557,
+    -0.09684078322297637,
+    -0.2932737832791736,
+    0.13319738582500787,
+    0.6572880780513006,
+    0.6048231236901103,
+    0.24383467461259,
+    0.03807794736387829,
+];
+
+pub const DB10: [f64; 20] = [
+    -1.3264202894521244e-5,
+    9.358867032006958e-5,
+    -0.00011646685512928541,
+    -0.0006858566949597122,
+    0.001992405295185057,
+    0.0013953517470529108,
+    -0.010733175483330637,
+    0.0036065535669563597,
+    0.033212674059340655,
+    -0.029457536821875296,
+    -0.07139414716639823,
+    0.09305736460357515,
+    0.12736934033578884,
+    -0.19594627437737294,
+    -0.2498464243273176,
+    0.2811723436605777,
+    0.6884590394536042,
+    0.5272011889317254,
+    0.18817680007769155,
+    0.026670057900555558,
+];
+
+pub const SYM2: [f64; 4] = [
+    -0.12940952255126045,
+    0.22414386804201333,
+    0.836516303737808,
+    0.4829629131445342,
+];
+
+pub const SYM3: [f64; 6] = [
+    0.03522629188570956,
+    -0.08544127388202663,
+    -0.13501102001025464,
+    0.4598775021184915,
+    0.8068915093110927,
+    0.3326705529500827,
+];
+
+pub const SYM4: [f64; 8] = [
+    -0.07576571478950227,
+    -0.02963552764600254,
+    0.49761866763277496,
+    0.8037387518051322,
+    0.2978577956053061,
+    -0.09921954357663351,
+    -0.012603967262031317,
+    0.032223100604051466,
+];
+
+pub const SYM5: [f64; 10] = [
+    0.02733306834499877,
+    0.02951949092570628,
+    -0.039134249302313795,
+    0.1993975339768556,
+    0.7234076904040406,
+    0.6339789634567923,
+    0.016602105764510666,
+    -0.17532808990805612,
+    -0.021101834024689053,
+    0.019538882735249823,
+];
+
+pub const SYM6: [f64; 12] = [
+    0.015404109327044831,
+    0.0034907120842221696,
+    -0.11799011114852011,
+    -0.04831174258569837,
+    0.49105594192797375,
+    0.7876411410286511,
+    0.33792942172816587,
+    -0.07263752278637654,
+    -0.021060292512370817,
+    0.04472490177078137,
+    0.0017677118642540099,
+    -0.007800708325032383,
+];
+
+pub const SYM7: [f64; 14] = [
+    0.0022918339540537705,
+    -0.0032832978474668143,
+    -0.018126605131338458,
+    0.020464207577546033,
+    0.04474234946835245,
+    -0.10101092086842028,
+    -0.056804476889666826,
+    0.4836109156822675,
+    0.7819215932917284,
+    0.36021846090626,
+    -0.06413128980738575,
+    -0.06490800354718848,
+    0.01721337630080452,
+    0.01201541928354919,
+];
+
+pub const SYM8: [f64; 16] = [
+    -0.003382415951005005,
+    -0.0005421323318000121,
+    0.03169508781152601,
+    0.007607487324976628,
+    -0.14329423835127264,
+    -0.06127335906781082,
+    0.48135965125905283,
+    0.7771857516996288,
+    0.3644418948361784,
+    -0.05194583810788152,
+    -0.027219029917103656,
+    0.049137179673730345,
+    0.003808752013894475,
+    -0.014952258337062197,
+    -0.0003029205147241357,
+    0.0018899503327676893,
+];
+
+pub const SYM9: [f64; 18] = [
+    0.0014009155259146534,
+    0.0006197808889855011,
+    -0.013271967781817105,
+    -0.01152821020767922,
+    0.030224878858275288,
+    0.0005834627461249419,
+    -0.0545689584308331,
+    0.23876091460730614,
+    0.717897082764412,
+    0.6173384491409335,
+    0.03527248803527092,
+    -0.19155083129728429,
+    -0.01823377077939538,
+    0.062077789302885655,
+    0.008859267493400266,
+    -0.010264064027633118,
+    -0.000473154498680042,
+    0.0010694900329086111,
+];
+
+pub const SYM10: [f64; 20] = [
+    -0.00041011591580439766,
+    0.00034014926631481025,
+    0.0050716491985317875,
+    -0.001140429795217326,
+    -0.023005461353497448,
+    -0.0008687521096894396,
+    0.03384235466357556,
+    -0.06708990780838234,
+    -0.08787871151197417,
+    0.3402160130234618,
+    0.7669548365606104,
+    0.5137098733480243,
+    -0.015019238839136952,
+    -0.12155210554854928,
+    0.026240365058449094,
+    0.04968612664694284,
+    0.0005956827837425072,
+    -0.0070567640625873,
+    0.0007154205420543393,
+    0.000862578226225972,
+];
+
+pub const COIF1: [f64; 6] = [
+    -0.01565572813546454,
+    -0.0727326195128539,
+    0.38486484686420286,
+    0.8525720202122554,
+    0.3378976624578092,
+    -0.0727326195128539,
+];
+
+pub const COIF2: [f64; 12] = [
+    -0.0007205494453645122,
+    -0.0018232088707029932,
+    0.0056114348193944995,
+    0.023680171946334084,
+    -0.0594344186464569,
+    -0.0764885990783064,
+    0.41700518442169254,
+    0.8127236354455423,
+    0.3861100668211622,
+    -0.06737255472196302,
+    -0.04146493678175915,
+    0.016387336463522112,
+];
+
+pub const COIF3: [f64; 18] = [
+    -3.459977283621256e-5,
+    -7.098330313814125e-5,
+    0.0004662169601128863,
+    0.0011175187708906016,
+    -0.0025745176887502236,
+    -0.00900797613666158,
+    0.015880544863615904,
+    0.03455502757306163,
+    -0.08230192710688598,
+    -0.07179982161931202,
+    0.42848347637761874,
+    0.7937772226256206,
+    0.4051769024096169,
+    -0.06112339000267287,
+    -0.0657719112818555,
+    0.023452696141836267,
+    0.007782596427325418,
+    -0.003793512864491014,
+];
@@ -7,4 +7,79 @@ pub enum ChartProcessingError {
 
     #[error("To perform this operation the chart should be in the STANDARD view")]
     NonStandard,
+
+    #[error("To perform this operation the chart should be in the FFT view")]
+    NoSpectrum,
+
+    #[error("Segment length should be between 1 and the number of chart samples")]
+    SegmentLength,
+
+    #[error("Overlap should be in the range [0, 100) percent")]
+    Overlap,
+
+    #[error("Decomposition level should be between 1 and {0} for this chart and wavelet")]
+    WaveletLevel(usize),
+
+    #[error("This chart does not keep the data needed for the inverse transform")]
+    NotInvertible,
+
+    #[error("Frequencies should satisfy 0 < low < high <= Nyquist frequency")]
+    FrequencyRange,
+
+    #[error("Sample rate should be a positive number")]
+    SampleRate,
+
+    #[error("Decimation factor should be at least 1")]
+    DecimationFactor,
+
+    #[error("Charts should have the same sample rate")]
+    SampleRateMismatch,
+
+    #[error("Peaks can only be found on spectrum charts")]
+    NotSpectrum,
+
+    #[error("Chart does not contain a cepstrum, use :ceps first")]
+    NoCepstrum,
+
+    #[error("Quefrency should be between {0} and {1} s for this cepstrum")]
+    Quefrency(f64, f64),
+
+    #[error("Charts should have the same transform")]
+    TransformMismatch,
+
+    #[error("Window should be an odd number of samples not longer than the chart")]
+    SmoothingWindow,
+
+    #[error("Polynomial order should be lower than the window length")]
+    PolynomialOrder,
+
+    #[error("Every sample was detected as invalid, nothing is left to repair from")]
+    NoValidSamples,
+
+    #[error("Crop range should satisfy start < end and contain at least two samples")]
+    CropRange,
+
+    #[error("Chart should contain at least two samples")]
+    NotEnoughSamples,
+
+    #[error("No band fits between the frequency resolution and the Nyquist frequency")]
+    NoOctaveBands,
+
+    #[error("Header records {0} blocks of {1} samples but the chart has {2}, give a block length")]
+    HeaderBlocks(usize, usize, usize),
+
+    #[error("Chart has no file header, give a block length")]
+    NoHeader,
+
+    #[error("Job was cancelled")]
+    Cancelled,
+
+    #[error("Another job is running, wait for it or press Esc to cancel it")]
+    JobRunning,
+
+    #[error("The cancelled job is still stopping, try again in a moment")]
+    JobCancelling,
+
+    #[error("Processing job stopped unexpectedly")]
+    JobPanicked,
 }
@@ -16,4 +16,7 @@ pub enum CommandError {
 
     #[error("No chart selected, use :of to open a chart file")]
     NoChart,
+
+    #[error("Invalid expression at position {1}: {0}")]
+    Expression(String, usize),
 }
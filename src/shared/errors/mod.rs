@@ -2,3 +2,4 @@ pub mod chart_processing;
 pub mod chart_view;
 pub mod commands;
 pub mod files;
+pub mod pipeline;
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PipelineError {
+    #[error("No such pipeline: {0}, use :pipe define or :pipe load first")]
+    UnknownPipeline(String),
+
+    #[error("Pipeline name should not be empty or contain '='")]
+    InvalidName,
+
+    #[error("Pipeline should contain at least one step")]
+    EmptyPipeline,
+
+    #[error("Invalid pipeline step: {0}, only commands creating charts can be used")]
+    InvalidStep(String),
+
+    #[error("Invalid pipeline definition: {0}")]
+    Syntax(String),
+
+    #[error("Pipeline step {0} created no chart")]
+    NoOutput(String),
+
+    #[error("Pipeline step {0} failed: {1}")]
+    StepFailed(String, String),
+
+    #[error("{0}: {1}")]
+    BatchFailed(String, String),
+
+    #[error("{0} already exists, use :pipe save {0} force to overwrite it")]
+    FileExists(String),
+}
@@ -1,8 +1,6 @@
-use std::{cell::RefCell, rc::Rc};
-
 use anyhow::Error;
 
-use crate::models::chart_view::chart::chart_model::ChartModel;
+use crate::models::chart_view::chart::{chart_model::ChartModel, shared_chart::SharedChart};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ApplicationMode {
@@ -25,7 +23,7 @@ pub struct ApplicationState {
     command: Option<String>,
     error: Option<String>,
 
-    charts: Vec<Rc<RefCell<ChartModel>>>,
+    charts: Vec<SharedChart>,
     current_chart_id: usize,
 }
 
@@ -147,7 +145,7 @@ impl ApplicationState {
     }
 
     // Chart management
-    pub fn charts(&self) -> Vec<Rc<RefCell<ChartModel>>> {
+    pub fn charts(&self) -> Vec<SharedChart> {
         self.charts.clone()
     }
 
@@ -155,12 +153,12 @@ impl ApplicationState {
         self.current_chart_id
     }
 
-    pub fn get_current_chart(&self) -> Option<Rc<RefCell<ChartModel>>> {
+    pub fn get_current_chart(&self) -> Option<SharedChart> {
         self.charts.get(self.current_chart_id).cloned()
     }
 
     pub fn add_chart(&mut self, data: ChartModel) {
-        self.charts.push(Rc::new(RefCell::new(data)));
+        self.charts.push(SharedChart::new(data));
         self.current_chart_id = self.charts.len() - 1;
     }
 
@@ -1,13 +1,27 @@
-use std::{cell::RefCell, rc::Rc};
-
 use crate::{
-    models::chart_view::{canvas_style::ChartViewStyle, chart::chart_model::ChartModel},
+    models::chart_view::{
+        canvas_style::ChartViewStyle,
+        chart::{shared_chart::SharedChart, signal_statistics::SignalStatistics},
+        pipeline::Pipeline,
+        statistics_scope::StatisticsScope,
+    },
     shared::constants::chart_view::{ZOOM_IN_COEFFICIENT, ZOOM_OUT_COEFFICIENT},
 };
+use std::collections::BTreeMap;
+
+/// Statistics of a chart over a range, kept until either changes.
+struct CachedStatistics {
+    chart: SharedChart,
+    range: Option<(f64, f64)>,
+    statistics: Option<SignalStatistics>,
+}
 
 pub struct ChartViewState {
-    current_chart: Option<Rc<RefCell<ChartModel>>>,
+    current_chart: Option<SharedChart>,
     canvas_style: ChartViewStyle,
+    statistics_scope: StatisticsScope,
+    statistics: Option<CachedStatistics>,
+    pipelines: BTreeMap<String, Pipeline>,
 }
 
 impl Default for ChartViewState {
@@ -21,6 +35,9 @@ impl ChartViewState {
         Self {
             current_chart: None,
             canvas_style: ChartViewStyle::new(),
+            statistics_scope: StatisticsScope::default(),
+            statistics: None,
+            pipelines: BTreeMap::new(),
         }
     }
 
@@ -28,39 +45,96 @@ impl ChartViewState {
         &self.canvas_style
     }
 
+    pub fn canvas_style_as_mut(&mut self) -> &mut ChartViewStyle {
+        &mut self.canvas_style
+    }
+
+    pub fn statistics_scope(&self) -> StatisticsScope {
+        self.statistics_scope
+    }
+
+    pub fn set_statistics_scope(&mut self, scope: StatisticsScope) {
+        self.statistics_scope = scope;
+    }
+
+    /// Statistics of a chart
+    ///
+    /// Rendering asks for them every frame, so they are only computed
+    /// again once the chart or the range differs from the last call.
+    ///
+    /// ---
+    ///
+    /// * `chart`: The chart the statistics describe.
+    /// * `range`: The x range of the statistics, the whole chart if `None`.
+    /// * `compute`: Computes the statistics when they are not cached.
+    pub fn statistics(
+        &mut self,
+        chart: &SharedChart,
+        range: Option<(f64, f64)>,
+        compute: impl FnOnce() -> Option<SignalStatistics>,
+    ) -> Option<SignalStatistics> {
+        let cached = self
+            .statistics
+            .as_ref()
+            .is_some_and(|cached| cached.chart.ptr_eq(chart) && cached.range == range);
+        if !cached {
+            self.statistics = Some(CachedStatistics {
+                chart: chart.clone(),
+                range,
+                statistics: compute(),
+            });
+        }
+        self.statistics
+            .as_ref()
+            .and_then(|cached| cached.statistics.clone())
+    }
+
+    pub fn pipeline(&self, name: &str) -> Option<&Pipeline> {
+        self.pipelines.get(name)
+    }
+
+    pub fn pipelines(&self) -> impl Iterator<Item = &Pipeline> {
+        self.pipelines.values()
+    }
+
+    /// Add a pipeline, replacing the one with the same name.
+    pub fn add_pipeline(&mut self, pipeline: Pipeline) {
+        self.pipelines.insert(pipeline.name.clone(), pipeline);
+    }
+
     pub fn x_min(&self) -> f64 {
         if let Some(chart) = &self.current_chart {
-            return chart.borrow().x_min;
+            return chart.read().x_min;
         }
         0.0
     }
 
     pub fn x_max(&self) -> f64 {
         if let Some(chart) = &self.current_chart {
-            return chart.borrow().x_max;
+            return chart.read().x_max;
         }
         0.0
     }
 
     pub fn y_min(&self) -> f64 {
         if let Some(chart) = &self.current_chart {
-            return chart.borrow().y_min;
+            return chart.read().y_min;
         }
         0.0
     }
 
     pub fn y_max(&self) -> f64 {
         if let Some(chart) = &self.current_chart {
-            return chart.borrow().y_max;
+            return chart.read().y_max;
         }
         0.0
     }
 
-    pub fn current_chart(&self) -> Option<Rc<RefCell<ChartModel>>> {
+    pub fn current_chart(&self) -> Option<SharedChart> {
         self.current_chart.clone()
     }
 
-    pub fn set_current_chart(&mut self, chart: Option<Rc<RefCell<ChartModel>>>) {
+    pub fn set_current_chart(&mut self, chart: Option<SharedChart>) {
         self.current_chart = chart;
     }
 
@@ -69,21 +143,22 @@ impl ChartViewState {
             return;
         };
 
-        let x_center = (current_chart.borrow().x_min + current_chart.borrow().x_max) / 2.0;
-        let x_half = (current_chart.borrow().x_max - current_chart.borrow().x_min) / 2.0;
+        let mut chart = current_chart.write();
+        let x_center = (chart.x_min + chart.x_max) / 2.0;
+        let x_half = (chart.x_max - chart.x_min) / 2.0;
         let x_half = if zoom_in {
             x_half * ZOOM_IN_COEFFICIENT * zoom_multiplier
         } else {
             x_half * ZOOM_OUT_COEFFICIENT / zoom_multiplier
         };
-        current_chart.borrow_mut().x_min = x_center - x_half;
-        current_chart.borrow_mut().x_max = x_center + x_half;
+        chart.x_min = x_center - x_half;
+        chart.x_max = x_center + x_half;
 
         let mut y_min = f64::INFINITY;
         let mut y_max = f64::NEG_INFINITY;
 
-        for point in &current_chart.borrow().data {
-            if point.x >= current_chart.borrow().x_min && point.x <= current_chart.borrow().x_max {
+        for point in &chart.data {
+            if point.x >= chart.x_min && point.x <= chart.x_max {
                 if point.y < y_min {
                     y_min = point.y;
                 }
@@ -94,8 +169,8 @@ impl ChartViewState {
         }
         if y_min.is_finite() && y_max.is_finite() {
             let padding = (y_max - y_min) * 0.05;
-            current_chart.borrow_mut().y_min = y_min - padding;
-            current_chart.borrow_mut().y_max = y_max + padding;
+            chart.y_min = y_min - padding;
+            chart.y_max = y_max + padding;
         }
     }
 
@@ -104,17 +179,15 @@ impl ChartViewState {
             return;
         };
 
-        let x_delta = current_chart.borrow().x_max - current_chart.borrow().x_min;
+        let mut chart = current_chart.write();
+        let x_delta = chart.x_max - chart.x_min;
+        let shift = x_delta / (self.canvas_style.canvas_steps) as f64 / 10.0 * points;
         if left {
-            current_chart.borrow_mut().x_min -=
-                x_delta / (self.canvas_style.canvas_steps) as f64 / 10.0 * points;
-            current_chart.borrow_mut().x_max -=
-                x_delta / (self.canvas_style.canvas_steps) as f64 / 10.0 * points;
+            chart.x_min -= shift;
+            chart.x_max -= shift;
         } else {
-            current_chart.borrow_mut().x_min +=
-                x_delta / (self.canvas_style.canvas_steps) as f64 / 10.0 * points;
-            current_chart.borrow_mut().x_max +=
-                x_delta / (self.canvas_style.canvas_steps) as f64 / 10.0 * points;
+            chart.x_min += shift;
+            chart.x_max += shift;
         }
     }
 }
@@ -0,0 +1,105 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::shared::{constants::command::CHART_REFERENCE_PREFIX, errors::commands::CommandError};
+
+/// Parse a required command argument
+///
+/// Returns `NotEnoughArguments` if the argument is missing and
+/// `InvalidArguments` if it can not be parsed into the requested type.
+///
+/// ---
+///
+/// * `args`: The whitespace separated command, including the command name.
+/// * `index`: The position of the argument in `args`.
+pub fn required_arg<T: FromStr>(args: &[&str], index: usize) -> Result<T, CommandError> {
+    let Some(arg) = args.get(index) else {
+        return Err(CommandError::NotEnoughArguments);
+    };
+    arg.parse::<T>()
+        .map_err(|_| CommandError::InvalidArguments(String::from(*arg)))
+}
+
+/// Parse an optional command argument
+///
+/// Returns `default` if the argument is missing and `InvalidArguments`
+/// if it can not be parsed into the requested type.
+///
+/// ---
+///
+/// * `args`: The whitespace separated command, including the command name.
+/// * `index`: The position of the argument in `args`.
+/// * `default`: The value to use when the argument is omitted.
+pub fn optional_arg<T: FromStr>(
+    args: &[&str],
+    index: usize,
+    default: T,
+) -> Result<T, CommandError> {
+    match args.get(index) {
+        Some(_) => required_arg(args, index),
+        None => Ok(default),
+    }
+}
+
+/// Parse a file path argument
+///
+/// Returns `NotEnoughArguments` if the argument is missing and
+/// `InvalidArguments` if no file exists at the path.
+///
+/// ---
+///
+/// * `args`: The whitespace separated command, including the command name.
+/// * `index`: The position of the argument in `args`.
+pub fn path_arg(args: &[&str], index: usize) -> Result<PathBuf, CommandError> {
+    let Some(arg) = args.get(index) else {
+        return Err(CommandError::NotEnoughArguments);
+    };
+    let path = Path::new(arg);
+    if !path.exists() {
+        return Err(CommandError::InvalidArguments(String::from(*arg)));
+    }
+    Ok(path.to_path_buf())
+}
+
+/// Reference to an opened chart
+///
+/// Written as `#N` for the chart with index `N` in the chart explorer,
+/// in every command taking a chart as well as in expressions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChartReference(pub usize);
+
+impl FromStr for ChartReference {
+    type Err = CommandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_prefix(CHART_REFERENCE_PREFIX)
+            .and_then(|index| index.parse::<usize>().ok())
+            .map(ChartReference)
+            .ok_or_else(|| CommandError::InvalidArguments(String::from(s)))
+    }
+}
+
+/// Chart operand of an arithmetic command
+///
+/// A chart reference `#N` or a scalar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartOperand {
+    Chart(usize),
+    Scalar(f64),
+}
+
+impl FromStr for ChartOperand {
+    type Err = CommandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with(CHART_REFERENCE_PREFIX) {
+            let ChartReference(index) = s.parse()?;
+            return Ok(ChartOperand::Chart(index));
+        }
+        s.parse::<f64>()
+            .map(ChartOperand::Scalar)
+            .map_err(|_| CommandError::InvalidArguments(String::from(s)))
+    }
+}
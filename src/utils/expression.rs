@@ -0,0 +1,553 @@
+use std::{
+    f64::consts::{E, PI},
+    str::FromStr,
+};
+
+use strum_macros::EnumString;
+
+use crate::{
+    models::chart_view::chart::signal_unit::SignalUnit,
+    shared::{
+        constants::command::{CHART_REFERENCE_PREFIX, EXPRESSION_CHART_PREFIX},
+        errors::commands::CommandError,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Power,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum Function {
+    Abs,
+    Sqrt,
+    Exp,
+    Ln,
+    Log10,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Sinh,
+    Cosh,
+    Tanh,
+    Floor,
+    Ceil,
+    Round,
+    Sign,
+    Min,
+    Max,
+    Pow,
+    Atan2,
+}
+
+impl Function {
+    pub fn arity(&self) -> usize {
+        match self {
+            Function::Min | Function::Max | Function::Pow | Function::Atan2 => 2,
+            _ => 1,
+        }
+    }
+
+    fn apply(&self, args: &[f64]) -> f64 {
+        let x = args[0];
+        match self {
+            Function::Abs => x.abs(),
+            Function::Sqrt => x.sqrt(),
+            Function::Exp => x.exp(),
+            Function::Ln => x.ln(),
+            Function::Log10 => x.log10(),
+            Function::Sin => x.sin(),
+            Function::Cos => x.cos(),
+            Function::Tan => x.tan(),
+            Function::Asin => x.asin(),
+            Function::Acos => x.acos(),
+            Function::Atan => x.atan(),
+            Function::Sinh => x.sinh(),
+            Function::Cosh => x.cosh(),
+            Function::Tanh => x.tanh(),
+            Function::Floor => x.floor(),
+            Function::Ceil => x.ceil(),
+            Function::Round => x.round(),
+            Function::Sign => x.signum(),
+            Function::Min => x.min(args[1]),
+            Function::Max => x.max(args[1]),
+            Function::Pow => x.powf(args[1]),
+            Function::Atan2 => x.atan2(args[1]),
+        }
+    }
+}
+
+/// Physical dimension of a value while inferring the unit of an expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dimension {
+    Scalar,
+    Unit(SignalUnit),
+    Unknown,
+}
+
+impl Dimension {
+    /// Dimension of the sum of two values, scalars taking the unit of the other value.
+    fn common(self, other: Dimension) -> Dimension {
+        match (self, other) {
+            (Dimension::Scalar, dimension) | (dimension, Dimension::Scalar) => dimension,
+            (Dimension::Unit(a), Dimension::Unit(b)) if a == b => self,
+            _ => Dimension::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Number(f64),
+    Time,
+    Chart(usize),
+    Negate(Box<Expression>),
+    Binary(Box<Expression>, BinaryOperator, Box<Expression>),
+    Call(Function, Vec<Expression>),
+}
+
+impl Expression {
+    /// Evaluate expression
+    ///
+    /// Compute the value of the expression at a single x coordinate.
+    ///
+    /// ---
+    ///
+    /// * `t`: The x coordinate the expression is evaluated at.
+    /// * `chart`: The value of the chart with the given explorer index at `t`.
+    pub fn evaluate(&self, t: f64, chart: &impl Fn(usize) -> f64) -> f64 {
+        match self {
+            Expression::Number(value) => *value,
+            Expression::Time => t,
+            Expression::Chart(index) => chart(*index),
+            Expression::Negate(operand) => -operand.evaluate(t, chart),
+            Expression::Binary(left, operator, right) => {
+                let (left, right) = (left.evaluate(t, chart), right.evaluate(t, chart));
+                match operator {
+                    BinaryOperator::Add => left + right,
+                    BinaryOperator::Subtract => left - right,
+                    BinaryOperator::Multiply => left * right,
+                    BinaryOperator::Divide => left / right,
+                    BinaryOperator::Power => left.powf(right),
+                }
+            }
+            Expression::Call(function, args) => {
+                let args: Vec<f64> = args.iter().map(|arg| arg.evaluate(t, chart)).collect();
+                function.apply(&args)
+            }
+        }
+    }
+
+    /// Unit of the expression value
+    ///
+    /// Sums of values in the same unit, scaling by constants and functions
+    /// keeping the magnitude such as `abs` or `max` preserve the unit of the
+    /// referenced charts, while transcendental functions give plain numbers.
+    /// Returns `None` when the value has no unit or its unit is not a
+    /// [`SignalUnit`], e.g. a product of two charts.
+    ///
+    /// ---
+    ///
+    /// * `chart_unit`: The unit of the chart with the given explorer index.
+    pub fn unit(&self, chart_unit: &impl Fn(usize) -> Option<SignalUnit>) -> Option<SignalUnit> {
+        match self.dimension(chart_unit) {
+            Dimension::Unit(unit) => Some(unit),
+            _ => None,
+        }
+    }
+
+    fn dimension(&self, chart_unit: &impl Fn(usize) -> Option<SignalUnit>) -> Dimension {
+        match self {
+            Expression::Number(_) => Dimension::Scalar,
+            Expression::Time => Dimension::Unknown,
+            Expression::Chart(index) => {
+                chart_unit(*index).map_or(Dimension::Unknown, Dimension::Unit)
+            }
+            Expression::Negate(operand) => operand.dimension(chart_unit),
+            Expression::Binary(left, operator, right) => {
+                let (left, right) = (left.dimension(chart_unit), right.dimension(chart_unit));
+                match (operator, left, right) {
+                    (BinaryOperator::Add | BinaryOperator::Subtract, _, _) => left.common(right),
+                    (BinaryOperator::Multiply, Dimension::Scalar, dimension)
+                    | (
+                        BinaryOperator::Multiply | BinaryOperator::Divide,
+                        dimension,
+                        Dimension::Scalar,
+                    ) => dimension,
+                    (BinaryOperator::Divide, Dimension::Unit(a), Dimension::Unit(b)) if a == b => {
+                        Dimension::Scalar
+                    }
+                    (BinaryOperator::Power, Dimension::Scalar, Dimension::Scalar) => {
+                        Dimension::Scalar
+                    }
+                    _ => Dimension::Unknown,
+                }
+            }
+            Expression::Call(function, args) => {
+                let mut dimensions = args.iter().map(|arg| arg.dimension(chart_unit));
+                match function {
+                    Function::Abs
+                    | Function::Floor
+                    | Function::Ceil
+                    | Function::Round
+                    | Function::Min
+                    | Function::Max => dimensions.fold(Dimension::Scalar, Dimension::common),
+                    Function::Sqrt | Function::Pow => {
+                        if dimensions.all(|dimension| dimension == Dimension::Scalar) {
+                            Dimension::Scalar
+                        } else {
+                            Dimension::Unknown
+                        }
+                    }
+                    _ => Dimension::Scalar,
+                }
+            }
+        }
+    }
+
+    /// Explorer indices of the charts referenced by the expression, in order of appearance.
+    pub fn chart_references(&self) -> Vec<usize> {
+        let mut references = Vec::new();
+        self.collect_references(&mut references);
+        references
+    }
+
+    fn collect_references(&self, references: &mut Vec<usize>) {
+        match self {
+            Expression::Chart(index) if !references.contains(index) => references.push(*index),
+            Expression::Negate(operand) => operand.collect_references(references),
+            Expression::Binary(left, _, right) => {
+                left.collect_references(references);
+                right.collect_references(references);
+            }
+            Expression::Call(_, args) => args
+                .iter()
+                .for_each(|arg| arg.collect_references(references)),
+            _ => {}
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = CommandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(tokenize(s)?, s.chars().count());
+        let expression = parser.expression()?;
+        match parser.peek() {
+            Some((_, position)) => Err(CommandError::Expression(
+                String::from("unexpected input"),
+                *position,
+            )),
+            None => Ok(expression),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Identifier(String),
+    Chart(usize),
+    Operator(char),
+    Comma,
+    OpenParen,
+    CloseParen,
+}
+
+/// Split the expression into tokens paired with their 1-based character position.
+fn tokenize(s: &str) -> Result<Vec<(Token, usize)>, CommandError> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let position = i + 1;
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_digit()
+                    || chars[i] == '.'
+                    || matches!(chars[i], 'e' | 'E')
+                    || (matches!(chars[i], '+' | '-') && matches!(chars[i - 1], 'e' | 'E')))
+            {
+                i += 1;
+            }
+            let literal: String = chars[start..i].iter().collect();
+            let value = literal.parse::<f64>().map_err(|_| {
+                CommandError::Expression(format!("invalid number {}", literal), position)
+            })?;
+            tokens.push((Token::Number(value), position));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push((
+                Token::Identifier(chars[start..i].iter().collect()),
+                position,
+            ));
+        } else if c == CHART_REFERENCE_PREFIX {
+            i += 1;
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            let index = chars[start..i]
+                .iter()
+                .collect::<String>()
+                .parse::<usize>()
+                .map_err(|_| {
+                    CommandError::Expression(String::from("expected chart index"), position)
+                })?;
+            tokens.push((Token::Chart(index), position));
+        } else {
+            let token = match c {
+                '+' | '-' | '*' | '/' | '^' => Token::Operator(c),
+                ',' => Token::Comma,
+                '(' => Token::OpenParen,
+                ')' => Token::CloseParen,
+                _ => {
+                    return Err(CommandError::Expression(
+                        format!("unexpected character {}", c),
+                        position,
+                    ));
+                }
+            };
+            tokens.push((token, position));
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser
+///
+/// expression := term (("+" | "-") term)*
+/// term       := unary (("*" | "/") unary)*
+/// unary      := ("-" | "+") unary | power
+/// power      := primary ("^" unary)?
+/// primary    := number | "t" | "pi" | "e" | ("#" | "c") index | function "(" arguments ")" | "(" expression ")"
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    index: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn new(tokens: Vec<(Token, usize)>, len: usize) -> Self {
+        Self {
+            tokens,
+            index: 0,
+            end: len + 1,
+        }
+    }
+
+    fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.index)
+    }
+
+    fn next(&mut self) -> Result<(Token, usize), CommandError> {
+        let token = self.tokens.get(self.index).cloned().ok_or_else(|| {
+            CommandError::Expression(String::from("unexpected end of expression"), self.end)
+        })?;
+        self.index += 1;
+        Ok(token)
+    }
+
+    fn next_operator(&mut self, operators: &[char]) -> Option<char> {
+        match self.peek() {
+            Some((Token::Operator(c), _)) if operators.contains(c) => {
+                let c = *c;
+                self.index += 1;
+                Some(c)
+            }
+            _ => None,
+        }
+    }
+
+    fn expect(&mut self, expected: Token, name: &str) -> Result<(), CommandError> {
+        let (token, position) = self.next()?;
+        if token == expected {
+            Ok(())
+        } else {
+            Err(CommandError::Expression(
+                format!("expected {}", name),
+                position,
+            ))
+        }
+    }
+
+    fn expression(&mut self) -> Result<Expression, CommandError> {
+        let mut left = self.term()?;
+        while let Some(c) = self.next_operator(&['+', '-']) {
+            let operator = if c == '+' {
+                BinaryOperator::Add
+            } else {
+                BinaryOperator::Subtract
+            };
+            left = Expression::Binary(Box::new(left), operator, Box::new(self.term()?));
+        }
+        Ok(left)
+    }
+
+    fn term(&mut self) -> Result<Expression, CommandError> {
+        let mut left = self.unary()?;
+        while let Some(c) = self.next_operator(&['*', '/']) {
+            let operator = if c == '*' {
+                BinaryOperator::Multiply
+            } else {
+                BinaryOperator::Divide
+            };
+            left = Expression::Binary(Box::new(left), operator, Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expression, CommandError> {
+        match self.next_operator(&['-', '+']) {
+            Some('-') => Ok(Expression::Negate(Box::new(self.unary()?))),
+            Some(_) => self.unary(),
+            None => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<Expression, CommandError> {
+        let base = self.primary()?;
+        if self.next_operator(&['^']).is_some() {
+            let exponent = self.unary()?;
+            return Ok(Expression::Binary(
+                Box::new(base),
+                BinaryOperator::Power,
+                Box::new(exponent),
+            ));
+        }
+        Ok(base)
+    }
+
+    fn primary(&mut self) -> Result<Expression, CommandError> {
+        let (token, position) = self.next()?;
+        match token {
+            Token::Number(value) => Ok(Expression::Number(value)),
+            Token::Chart(index) => Ok(Expression::Chart(index)),
+            Token::OpenParen => {
+                let expression = self.expression()?;
+                self.expect(Token::CloseParen, "closing parenthesis")?;
+                Ok(expression)
+            }
+            Token::Identifier(name) => self.identifier(&name, position),
+            _ => Err(CommandError::Expression(
+                String::from("expected a value"),
+                position,
+            )),
+        }
+    }
+
+    fn identifier(&mut self, name: &str, position: usize) -> Result<Expression, CommandError> {
+        if let Ok(function) = Function::from_str(name) {
+            self.expect(Token::OpenParen, "opening parenthesis")?;
+            let mut args = vec![self.expression()?];
+            while matches!(self.peek(), Some((Token::Comma, _))) {
+                self.index += 1;
+                args.push(self.expression()?);
+            }
+            self.expect(Token::CloseParen, "closing parenthesis")?;
+            if args.len() != function.arity() {
+                return Err(CommandError::Expression(
+                    format!("{} expects {} argument(s)", name, function.arity()),
+                    position,
+                ));
+            }
+            return Ok(Expression::Call(function, args));
+        }
+        match name {
+            "t" => Ok(Expression::Time),
+            "pi" => Ok(Expression::Number(PI)),
+            "e" => Ok(Expression::Number(E)),
+            _ => name
+                .strip_prefix(EXPRESSION_CHART_PREFIX)
+                .and_then(|index| index.parse::<usize>().ok())
+                .map(Expression::Chart)
+                .ok_or_else(|| {
+                    CommandError::Expression(format!("unknown identifier {}", name), position)
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_at(s: &str) -> (String, usize) {
+        match s.parse::<Expression>() {
+            Err(CommandError::Expression(message, position)) => (message, position),
+            other => panic!("{} parsed to {:?}", s, other),
+        }
+    }
+
+    #[test]
+    fn parses_requested_example_with_both_chart_references() {
+        let expression: Expression = "abs(c0 - 0.5*#2) + sin(2*pi*50*t)".parse().unwrap();
+        assert_eq!(expression.chart_references(), vec![0, 2]);
+
+        let charts = |index: usize| if index == 0 { 1.0 } else { 4.0 };
+        let value = expression.evaluate(0.005, &charts);
+        assert!((value - 2.0).abs() < 1e-12, "{}", value);
+    }
+
+    #[test]
+    fn respects_precedence_and_right_associative_power() {
+        let expression: Expression = "-2^2 + 3*4 - 2^3^2 / 64".parse().unwrap();
+        assert_eq!(expression.evaluate(0.0, &|_| 0.0), -4.0 + 12.0 - 8.0);
+    }
+
+    #[test]
+    fn reports_error_positions() {
+        let cases = [
+            ("1 + foo", "unknown identifier foo", 5),
+            ("c0 + #", "expected chart index", 6),
+            ("(t + 1", "unexpected end of expression", 7),
+            ("t $ 2", "unexpected character $", 3),
+            ("t 2", "unexpected input", 3),
+            ("max(t)", "max expects 2 argument(s)", 1),
+            ("sin t", "expected opening parenthesis", 5),
+        ];
+        for (source, message, position) in cases {
+            assert_eq!(error_at(source), (String::from(message), position));
+        }
+    }
+
+    #[test]
+    fn infers_unit_of_unit_preserving_expressions() {
+        let units = |index: usize| match index {
+            0 | 2 => Some(SignalUnit::Gravity),
+            1 => Some(SignalUnit::MillimetersPerSecond),
+            _ => None,
+        };
+        let cases = [
+            ("abs(c0 - #2) + sin(2*pi*t)", Some(SignalUnit::Gravity)),
+            ("-max(c0, 2) / 4", Some(SignalUnit::Gravity)),
+            ("c0 + c1", None),
+            ("c0 * c2", None),
+            ("c0 / c2", None),
+            ("c0 * t", None),
+            ("c3 + 1", None),
+        ];
+        for (source, unit) in cases {
+            let expression: Expression = source.parse().unwrap();
+            assert_eq!(expression.unit(&units), unit, "{}", source);
+        }
+    }
+}
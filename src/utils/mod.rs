@@ -1 +1,3 @@
 pub mod cli_helper;
+pub mod command_args;
+pub mod expression;